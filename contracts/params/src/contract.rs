@@ -5,15 +5,26 @@ use cw2::set_contract_version;
 use mars_owner::OwnerInit::SetInitialOwner;
 
 use crate::{
-    emergency_powers::{disable_borrowing, disallow_coin, set_zero_deposit_cap, set_zero_max_ltv},
+    emergency_powers::{
+        disable_borrowing, disallow_coin, set_zero_borrow_cap, set_zero_deposit_cap,
+        set_zero_max_ltv,
+    },
     error::ContractResult,
-    execute::{assert_thf, update_asset_params, update_target_health_factor, update_vault_config},
+    execute::{
+        assert_thf, update_asset_category, update_asset_params, update_target_health_factor,
+        update_vault_config,
+    },
+    migrations::{backfill_asset_params, rebuild_indexes},
     msg::{
-        CmEmergencyUpdate, EmergencyUpdate, ExecuteMsg, InstantiateMsg, QueryMsg,
+        CmEmergencyUpdate, EmergencyUpdate, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
         RedBankEmergencyUpdate,
     },
-    query::{query_all_asset_params, query_all_vault_configs, query_vault_config},
-    state::{ASSET_PARAMS, OWNER, TARGET_HEALTH_FACTOR},
+    query::{
+        query_all_asset_categories, query_all_asset_params, query_all_vault_configs,
+        query_asset_params_batch, query_isolation_check, query_vault_config,
+        query_vault_configs_batch,
+    },
+    state::{asset_params, ASSET_CATEGORIES, OWNER, TARGET_HEALTH_FACTOR},
 };
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -54,11 +65,15 @@ pub fn execute(
         ExecuteMsg::UpdateAssetParams(update) => update_asset_params(deps, info, update),
         ExecuteMsg::UpdateTargetHealthFactor(mcf) => update_target_health_factor(deps, info, mcf),
         ExecuteMsg::UpdateVaultConfig(update) => update_vault_config(deps, info, update),
+        ExecuteMsg::UpdateAssetCategory(update) => update_asset_category(deps, info, update),
         ExecuteMsg::EmergencyUpdate(update) => match update {
             EmergencyUpdate::RedBank(rb_u) => match rb_u {
                 RedBankEmergencyUpdate::DisableBorrowing(denom) => {
                     disable_borrowing(deps, info, &denom)
                 }
+                RedBankEmergencyUpdate::SetZeroBorrowCap(denom) => {
+                    set_zero_borrow_cap(deps, info, &denom)
+                }
             },
             EmergencyUpdate::CreditManager(rv_u) => match rv_u {
                 CmEmergencyUpdate::DisallowCoin(denom) => disallow_coin(deps, info, &denom),
@@ -77,19 +92,50 @@ pub fn query(deps: Deps, _: Env, msg: QueryMsg) -> ContractResult<Binary> {
         QueryMsg::Owner {} => to_binary(&OWNER.query(deps.storage)?),
         QueryMsg::AssetParams {
             denom,
-        } => to_binary(&ASSET_PARAMS.load(deps.storage, &denom)?),
+        } => to_binary(&asset_params().load(deps.storage, &denom)?),
         QueryMsg::AllAssetParams {
             start_after,
             limit,
-        } => to_binary(&query_all_asset_params(deps, start_after, limit)?),
+            filter,
+        } => to_binary(&query_all_asset_params(deps, start_after, limit, filter)?),
+        QueryMsg::AssetParamsBatch {
+            denoms,
+        } => to_binary(&query_asset_params_batch(deps, denoms)?),
         QueryMsg::VaultConfig {
             address,
         } => to_binary(&query_vault_config(deps, &address)?),
+        QueryMsg::VaultConfigsBatch {
+            addresses,
+        } => to_binary(&query_vault_configs_batch(deps, addresses)?),
         QueryMsg::AllVaultConfigs {
             start_after,
             limit,
-        } => to_binary(&query_all_vault_configs(deps, start_after, limit)?),
+            filter,
+        } => to_binary(&query_all_vault_configs(deps, start_after, limit, filter)?),
+        QueryMsg::AssetCategory {
+            name,
+        } => to_binary(&ASSET_CATEGORIES.load(deps.storage, &name)?),
+        QueryMsg::AllAssetCategories {
+            start_after,
+            limit,
+        } => to_binary(&query_all_asset_categories(deps, start_after, limit)?),
         QueryMsg::TargetHealthFactor {} => to_binary(&TARGET_HEALTH_FACTOR.load(deps.storage)?),
+        QueryMsg::IsolationCheck {
+            collateral_denom,
+            debt_denom,
+        } => to_binary(&query_isolation_check(deps, &collateral_denom, &debt_denom)?),
     };
     res.map_err(Into::into)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _: Env, msg: MigrateMsg) -> ContractResult<Response> {
+    set_contract_version(deps.storage, format!("crates.io:{CONTRACT_NAME}"), CONTRACT_VERSION)?;
+    let updated_assets = backfill_asset_params(deps.storage, msg.default_reserve_factor)?;
+    rebuild_indexes(deps.storage)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("default_reserve_factor", msg.default_reserve_factor.to_string())
+        .add_attribute("updated_assets", updated_assets.to_string()))
+}
@@ -2,7 +2,7 @@ use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response, Uint128};
 
 use crate::{
     error::ContractError,
-    state::{ASSET_PARAMS, OWNER, VAULT_CONFIGS},
+    state::{asset_params, save_asset_params, save_vault_config, vault_configs, OWNER},
 };
 
 pub fn disable_borrowing(
@@ -12,9 +12,9 @@ pub fn disable_borrowing(
 ) -> Result<Response, ContractError> {
     OWNER.assert_emergency_owner(deps.storage, &info.sender)?;
 
-    let mut params = ASSET_PARAMS.load(deps.storage, denom)?;
+    let mut params = asset_params().load(deps.storage, denom)?;
     params.red_bank.borrow_enabled = false;
-    ASSET_PARAMS.save(deps.storage, denom, &params)?;
+    save_asset_params(deps.storage, &params)?;
 
     let response = Response::new()
         .add_attribute("action", "emergency_disable_borrowing")
@@ -23,6 +23,24 @@ pub fn disable_borrowing(
     Ok(response)
 }
 
+pub fn set_zero_borrow_cap(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: &str,
+) -> Result<Response, ContractError> {
+    OWNER.assert_emergency_owner(deps.storage, &info.sender)?;
+
+    let mut params = asset_params().load(deps.storage, denom)?;
+    params.red_bank.borrow_cap = Uint128::zero();
+    save_asset_params(deps.storage, &params)?;
+
+    let response = Response::new()
+        .add_attribute("action", "emergency_set_zero_borrow_cap")
+        .add_attribute("denom", denom.to_string());
+
+    Ok(response)
+}
+
 pub fn disallow_coin(
     deps: DepsMut,
     info: MessageInfo,
@@ -30,9 +48,9 @@ pub fn disallow_coin(
 ) -> Result<Response, ContractError> {
     OWNER.assert_emergency_owner(deps.storage, &info.sender)?;
 
-    let mut params = ASSET_PARAMS.load(deps.storage, denom)?;
+    let mut params = asset_params().load(deps.storage, denom)?;
     params.credit_manager.whitelisted = false;
-    ASSET_PARAMS.save(deps.storage, denom, &params)?;
+    save_asset_params(deps.storage, &params)?;
 
     let response = Response::new()
         .add_attribute("action", "emergency_disallow_coin")
@@ -50,9 +68,9 @@ pub fn set_zero_max_ltv(
 
     let vault_addr = deps.api.addr_validate(vault)?;
 
-    let mut config = VAULT_CONFIGS.load(deps.storage, &vault_addr)?;
+    let mut config = vault_configs().load(deps.storage, &vault_addr)?;
     config.max_loan_to_value = Decimal::zero();
-    VAULT_CONFIGS.save(deps.storage, &vault_addr, &config)?;
+    save_vault_config(deps.storage, &config)?;
 
     let response = Response::new()
         .add_attribute("action", "emergency_set_zero_max_ltv")
@@ -70,9 +88,9 @@ pub fn set_zero_deposit_cap(
 
     let vault_addr = deps.api.addr_validate(vault)?;
 
-    let mut config = VAULT_CONFIGS.load(deps.storage, &vault_addr)?;
+    let mut config = vault_configs().load(deps.storage, &vault_addr)?;
     config.deposit_cap.amount = Uint128::zero();
-    VAULT_CONFIGS.save(deps.storage, &vault_addr, &config)?;
+    save_vault_config(deps.storage, &config)?;
 
     let response = Response::new()
         .add_attribute("action", "emergency_set_zero_deposit_cap")
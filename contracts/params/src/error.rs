@@ -1,4 +1,6 @@
-use cosmwasm_std::{DecimalRangeExceeded, StdError};
+use cosmwasm_std::{
+    CheckedFromRatioError, Decimal, DecimalRangeExceeded, OverflowError, StdError, Uint128,
+};
 use mars_owner::OwnerError;
 pub use mars_utils::error::ValidationError;
 use thiserror::Error;
@@ -13,9 +15,35 @@ pub enum ContractError {
     #[error("{0}")]
     DecimalRangeExceeded(#[from] DecimalRangeExceeded),
 
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    CheckedFromRatio(#[from] CheckedFromRatioError),
+
     #[error("{0}")]
     Owner(#[from] OwnerError),
 
     #[error("{0}")]
     Validation(#[from] ValidationError),
+
+    #[error("Asset category {name} is referenced by HLS correlations of {referenced_by}")]
+    AssetCategoryInUse {
+        name: String,
+        referenced_by: String,
+    },
+
+    #[error("Credit Manager exposure cap for {denom} exceeded: {amount} > {cap}")]
+    ExposureCapExceeded {
+        denom: String,
+        cap: Uint128,
+        amount: Uint128,
+    },
+
+    #[error("Max concentration for {denom} exceeded: {concentration} > {max_concentration}")]
+    ConcentrationLimitExceeded {
+        denom: String,
+        max_concentration: Decimal,
+        concentration: Decimal,
+    },
 }
@@ -1,10 +1,14 @@
-use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response};
+use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Order, Response, Storage, Uint128};
 use mars_utils::error::ValidationError;
 
 use crate::{
     error::{ContractError, ContractResult},
-    msg::{AssetParamsUpdate, VaultConfigUpdate},
-    state::{ASSET_PARAMS, OWNER, TARGET_HEALTH_FACTOR, VAULT_CONFIGS},
+    msg::{AssetCategoryUpdate, AssetParamsUpdate, VaultConfigUpdate},
+    state::{
+        remove_asset_category, save_asset_category, save_asset_params, save_vault_config,
+        ASSET_CATEGORIES, CATEGORY_REFERENCES, OWNER, TARGET_HEALTH_FACTOR,
+    },
+    types::hls::HlsParamsBase,
 };
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -41,8 +45,11 @@ pub fn update_asset_params(
             params: unchecked,
         } => {
             let params = unchecked.check(deps.api)?;
+            if let Some(hls) = params.credit_manager.hls.as_ref() {
+                assert_categories_exist(deps.storage, hls)?;
+            }
 
-            ASSET_PARAMS.save(deps.storage, &params.denom, &params)?;
+            save_asset_params(deps.storage, &params)?;
             response = response
                 .add_attribute("action_type", "add_or_update")
                 .add_attribute("denom", params.denom);
@@ -66,7 +73,10 @@ pub fn update_vault_config(
             config,
         } => {
             let checked = config.check(deps.api)?;
-            VAULT_CONFIGS.save(deps.storage, &checked.addr, &checked)?;
+            if let Some(hls) = checked.hls.as_ref() {
+                assert_categories_exist(deps.storage, hls)?;
+            }
+            save_vault_config(deps.storage, &checked)?;
             response = response
                 .add_attribute("action_type", "add_or_update")
                 .add_attribute("addr", checked.addr);
@@ -76,6 +86,71 @@ pub fn update_vault_config(
     Ok(response)
 }
 
+pub fn update_asset_category(
+    deps: DepsMut,
+    info: MessageInfo,
+    update: AssetCategoryUpdate,
+) -> ContractResult<Response> {
+    OWNER.assert_owner(deps.storage, &info.sender)?;
+
+    let mut response = Response::new().add_attribute("action", "update_asset_category");
+
+    match update {
+        AssetCategoryUpdate::AddOrUpdate {
+            category,
+        } => {
+            category.validate()?;
+            save_asset_category(deps.storage, &category)?;
+            response = response
+                .add_attribute("action_type", "add_or_update")
+                .add_attribute("name", category.name);
+        }
+        AssetCategoryUpdate::Remove {
+            name,
+        } => {
+            assert_category_not_referenced(deps.storage, &name)?;
+            ASSET_CATEGORIES.load(deps.storage, &name)?;
+            remove_asset_category(deps.storage, &name)?;
+            response = response.add_attribute("action_type", "remove").add_attribute("name", name);
+        }
+    }
+
+    Ok(response)
+}
+
+/// Asset categories referenced by HLS correlations must be stored
+fn assert_categories_exist<T>(
+    storage: &dyn Storage,
+    hls: &HlsParamsBase<T>,
+) -> Result<(), ValidationError> {
+    for name in hls.categories() {
+        if !ASSET_CATEGORIES.has(storage, name) {
+            return Err(ValidationError::InvalidParam {
+                param_name: "correlations".to_string(),
+                invalid_value: name.to_string(),
+                predicate: "existing asset category".to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn assert_category_not_referenced(storage: &dyn Storage, name: &str) -> ContractResult<()> {
+    let referrer = CATEGORY_REFERENCES
+        .prefix(name)
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .transpose()?;
+
+    match referrer {
+        Some(referenced_by) => Err(ContractError::AssetCategoryInUse {
+            name: name.to_string(),
+            referenced_by,
+        }),
+        None => Ok(()),
+    }
+}
+
 pub fn assert_thf(thf: Decimal) -> Result<(), ContractError> {
     if thf < Decimal::one() || thf > Decimal::from_atomics(2u128, 0u32)? {
         return Err(ValidationError::InvalidParam {
@@ -116,3 +191,65 @@ pub fn assert_hls_lqt_gt_max_ltv(
     }
     Ok(())
 }
+
+/// hls_max_loan_to_value should be greater than or equal to max_loan_to_value
+pub fn assert_hls_max_ltv_gte_max_ltv(
+    max_ltv: Decimal,
+    hls_max_ltv: Decimal,
+) -> Result<(), ValidationError> {
+    if hls_max_ltv < max_ltv {
+        return Err(ValidationError::InvalidParam {
+            param_name: "hls_max_loan_to_value".to_string(),
+            invalid_value: hls_max_ltv.to_string(),
+            predicate: format!(">= {} (max LTV)", max_ltv),
+        });
+    }
+    Ok(())
+}
+
+/// hls_liquidation_threshold should be greater than or equal to liquidation_threshold
+pub fn assert_hls_lqt_gte_lqt(
+    liq_threshold: Decimal,
+    hls_liq_threshold: Decimal,
+) -> Result<(), ValidationError> {
+    if hls_liq_threshold < liq_threshold {
+        return Err(ValidationError::InvalidParam {
+            param_name: "hls_liquidation_threshold".to_string(),
+            invalid_value: hls_liq_threshold.to_string(),
+            predicate: format!(">= {} (liquidation threshold)", liq_threshold),
+        });
+    }
+    Ok(())
+}
+
+/// liquidation_threshold * (1 + max_lb) should be less than one, otherwise liquidating a position
+/// at the threshold pays out more collateral than the debt it repays
+pub fn assert_lqt_with_max_lb_lt_one(
+    liq_threshold: Decimal,
+    max_lb: Decimal,
+    param_name: &str,
+) -> Result<(), ValidationError> {
+    if liq_threshold * (Decimal::one() + max_lb) >= Decimal::one() {
+        return Err(ValidationError::InvalidParam {
+            param_name: param_name.to_string(),
+            invalid_value: liq_threshold.to_string(),
+            predicate: format!("< 1 / (1 + {} (max LB))", max_lb),
+        });
+    }
+    Ok(())
+}
+
+/// borrow_cap should be less than or equal to deposit_cap, nothing more can be lent out than deposited
+pub fn assert_borrow_cap_le_deposit_cap(
+    deposit_cap: Uint128,
+    borrow_cap: Uint128,
+) -> Result<(), ValidationError> {
+    if borrow_cap > deposit_cap {
+        return Err(ValidationError::InvalidParam {
+            param_name: "borrow_cap".to_string(),
+            invalid_value: borrow_cap.to_string(),
+            predicate: format!("<= {} (deposit cap)", deposit_cap),
+        });
+    }
+    Ok(())
+}
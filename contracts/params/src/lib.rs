@@ -2,6 +2,7 @@ pub mod contract;
 pub mod emergency_powers;
 pub mod error;
 pub mod execute;
+pub mod migrations;
 pub mod msg;
 pub mod query;
 pub mod state;
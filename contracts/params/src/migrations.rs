@@ -0,0 +1,122 @@
+use cosmwasm_std::{Decimal, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use mars_utils::helpers::decimal_param_lt_one;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::ContractResult,
+    state::{
+        asset_params, save_category_references, vault_configs, AssetParamsCounts,
+        VaultConfigsCounts, ASSET_CATEGORIES, ASSET_CATEGORIES_COUNT, ASSET_PARAMS_COUNTS,
+        CATEGORY_REFERENCES, VAULT_CONFIGS_COUNTS,
+    },
+};
+
+/// Red Bank settings as stored by previous contract versions, which may lack fields introduced
+/// since then
+#[derive(Serialize, Deserialize)]
+struct StoredRedBankSettings {
+    deposit_cap: Uint128,
+    borrow_cap: Option<Uint128>,
+    reserve_factor: Option<Decimal>,
+}
+
+/// Credit Manager settings as stored by previous contract versions, which may lack fields
+/// introduced since then
+#[derive(Serialize, Deserialize)]
+struct StoredCmSettings {
+    exposure_cap: Option<Uint128>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredAssetParams {
+    credit_manager: StoredCmSettings,
+    red_bank: StoredRedBankSettings,
+}
+
+/// Raw entries of `asset_params()`, used to tell missing fields apart from explicitly set ones
+const STORED_ASSET_PARAMS: Map<&str, StoredAssetParams> = Map::new("asset_params");
+
+/// Fill in the fields missing from asset params stored before they were introduced:
+/// - `exposure_cap` is set to unlimited, so existing assets keep their current behaviour
+/// - `borrow_cap` is set to the deposit cap, so existing markets keep their current behaviour
+/// - `reserve_factor` is set to the provided default
+///
+/// Values that were explicitly stored, including zero, are left untouched.
+/// Returns the number of updated assets.
+pub fn backfill_asset_params(
+    storage: &mut dyn Storage,
+    default_reserve_factor: Decimal,
+) -> ContractResult<usize> {
+    decimal_param_lt_one(default_reserve_factor, "reserve_factor")?;
+
+    let stored = STORED_ASSET_PARAMS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut updated = 0;
+    for (denom, stored) in stored {
+        let mut params = asset_params().load(storage, &denom)?;
+        let mut outdated = false;
+
+        if stored.credit_manager.exposure_cap.is_none() {
+            params.credit_manager.exposure_cap = Uint128::MAX;
+            outdated = true;
+        }
+
+        if stored.red_bank.borrow_cap.is_none() {
+            params.red_bank.borrow_cap = stored.red_bank.deposit_cap;
+            outdated = true;
+        }
+
+        if stored.red_bank.reserve_factor.is_none() {
+            params.red_bank.reserve_factor = default_reserve_factor;
+            outdated = true;
+        }
+
+        if outdated {
+            asset_params().save(storage, &denom, &params)?;
+            updated += 1;
+        }
+    }
+
+    Ok(updated)
+}
+
+/// Re-save every asset param and vault config so secondary indexes are populated for entries
+/// stored before the indexes existed, and recompute the stored entry counts and category references
+pub fn rebuild_indexes(storage: &mut dyn Storage) -> ContractResult<()> {
+    let references = CATEGORY_REFERENCES
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (name, referrer) in references.iter() {
+        CATEGORY_REFERENCES.remove(storage, (name, referrer));
+    }
+
+    let params = asset_params()
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut params_counts = AssetParamsCounts::default();
+    for (denom, params) in params.iter() {
+        asset_params().save(storage, denom, params)?;
+        params_counts.add(params);
+        save_category_references(storage, denom, &params.credit_manager.hls)?;
+    }
+    ASSET_PARAMS_COUNTS.save(storage, &params_counts)?;
+
+    let configs = vault_configs()
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut configs_counts = VaultConfigsCounts::default();
+    for (addr, config) in configs.iter() {
+        vault_configs().save(storage, addr, config)?;
+        configs_counts.add(config);
+        save_category_references(storage, addr.as_str(), &config.hls)?;
+    }
+    VAULT_CONFIGS_COUNTS.save(storage, &configs_counts)?;
+
+    let categories_count = ASSET_CATEGORIES.keys_raw(storage, None, None, Order::Ascending).count();
+    ASSET_CATEGORIES_COUNT.save(storage, &(categories_count as u64))?;
+
+    Ok(())
+}
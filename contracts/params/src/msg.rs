@@ -1,8 +1,14 @@
+use std::collections::BTreeMap;
+
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Decimal, Uint128};
 use mars_owner::OwnerUpdate;
 
-use crate::types::{asset::AssetParamsUnchecked, vault::VaultConfigUnchecked};
+use crate::types::{
+    asset::{AssetParams, AssetParamsUnchecked},
+    category::AssetCategory,
+    vault::{VaultConfig, VaultConfigUnchecked},
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -12,12 +18,19 @@ pub struct InstantiateMsg {
     pub target_health_factor: Decimal,
 }
 
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Reserve factor applied to stored asset params that don't have one yet
+    pub default_reserve_factor: Decimal,
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     UpdateOwner(OwnerUpdate),
     UpdateTargetHealthFactor(Decimal),
     UpdateAssetParams(AssetParamsUpdate),
     UpdateVaultConfig(VaultConfigUpdate),
+    UpdateAssetCategory(AssetCategoryUpdate),
     EmergencyUpdate(EmergencyUpdate),
 }
 
@@ -32,10 +45,18 @@ pub enum QueryMsg {
         denom: String,
     },
 
-    #[returns(Vec<crate::types::asset::AssetParams>)]
+    #[returns(PaginationResponse<crate::types::asset::AssetParams>)]
     AllAssetParams {
         start_after: Option<String>,
         limit: Option<u32>,
+        /// Only return params matching the filter, looked up through a secondary index
+        filter: Option<AssetParamsFilter>,
+    },
+
+    /// Params of each requested denom, `null` for denoms without params
+    #[returns(AssetParamsBatchResponse)]
+    AssetParamsBatch {
+        denoms: Vec<String>,
     },
 
     #[returns(crate::types::vault::VaultConfig)]
@@ -44,14 +65,76 @@ pub enum QueryMsg {
         address: String,
     },
 
-    #[returns(Vec<crate::types::vault::VaultConfig>)]
+    #[returns(PaginationResponse<crate::types::vault::VaultConfig>)]
     AllVaultConfigs {
         start_after: Option<String>,
         limit: Option<u32>,
+        /// Only return configs matching the filter, looked up through a secondary index
+        filter: Option<VaultConfigsFilter>,
+    },
+
+    /// Config of each requested vault, `null` for invalid addresses and vaults without a config
+    #[returns(VaultConfigsBatchResponse)]
+    VaultConfigsBatch {
+        addresses: Vec<String>,
+    },
+
+    #[returns(crate::types::category::AssetCategory)]
+    AssetCategory {
+        name: String,
+    },
+
+    #[returns(PaginationResponse<crate::types::category::AssetCategory>)]
+    AllAssetCategories {
+        start_after: Option<String>,
+        limit: Option<u32>,
     },
 
     #[returns(Decimal)]
     TargetHealthFactor {},
+
+    /// Whether `debt_denom` can be borrowed against `collateral_denom` under isolation rules
+    #[returns(IsolationCheckResponse)]
+    IsolationCheck {
+        collateral_denom: String,
+        debt_denom: String,
+    },
+}
+
+#[cw_serde]
+pub enum AssetParamsFilter {
+    /// Coins whitelisted in Credit Manager
+    CmWhitelisted,
+    /// Red Bank markets with borrowing enabled
+    BorrowEnabled,
+    /// Assets with HLS params
+    HlsEnabled,
+}
+
+#[cw_serde]
+pub enum VaultConfigsFilter {
+    Whitelisted,
+    NonZeroDepositCap,
+}
+
+#[cw_serde]
+pub struct PaginationResponse<T> {
+    pub data: Vec<T>,
+    /// Value to pass as `start_after` to get the next page, `null` if this is the last page
+    pub next_start_after: Option<String>,
+    /// Number of items across all pages (matching the filter, if any)
+    pub total_count: u64,
+}
+
+pub type AssetParamsBatchResponse = BTreeMap<String, Option<AssetParams>>;
+
+pub type VaultConfigsBatchResponse = BTreeMap<String, Option<VaultConfig>>;
+
+#[cw_serde]
+pub struct IsolationCheckResponse {
+    pub allowed: bool,
+    /// Max total debt backed by the collateral, if it is isolated
+    pub debt_ceiling: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -68,6 +151,17 @@ pub enum VaultConfigUpdate {
     },
 }
 
+#[cw_serde]
+pub enum AssetCategoryUpdate {
+    AddOrUpdate {
+        category: AssetCategory,
+    },
+    /// Fails if the category is still referenced by an HLS correlation
+    Remove {
+        name: String,
+    },
+}
+
 #[cw_serde]
 pub enum CmEmergencyUpdate {
     SetZeroMaxLtvOnVault(String),
@@ -78,6 +172,7 @@ pub enum CmEmergencyUpdate {
 #[cw_serde]
 pub enum RedBankEmergencyUpdate {
     DisableBorrowing(String),
+    SetZeroBorrowCap(String),
 }
 
 #[cw_serde]
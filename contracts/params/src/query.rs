@@ -1,51 +1,172 @@
-use cosmwasm_std::{Addr, Deps, Order, StdResult};
+use cosmwasm_std::{Deps, Order, StdResult};
 use cw_storage_plus::Bound;
 
 use crate::{
-    state::{ASSET_PARAMS, VAULT_CONFIGS},
-    types::{asset::AssetParams, vault::VaultConfig},
+    msg::{
+        AssetParamsBatchResponse, AssetParamsFilter, IsolationCheckResponse, PaginationResponse,
+        VaultConfigsBatchResponse, VaultConfigsFilter,
+    },
+    state::{
+        asset_params, vault_configs, ASSET_CATEGORIES, ASSET_CATEGORIES_COUNT, ASSET_PARAMS_COUNTS,
+        INDEX_TRUE, VAULT_CONFIGS_COUNTS,
+    },
+    types::{asset::AssetParams, category::AssetCategory, vault::VaultConfig},
 };
 
 pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 30;
+
+/// Take a page of at most `limit` items, using the key of the last returned item as the cursor
+/// for the next page if there are more items left.
+fn paginate<K, T>(
+    mut items: impl Iterator<Item = StdResult<(K, T)>>,
+    limit: Option<u32>,
+    total_count: u64,
+) -> StdResult<PaginationResponse<T>>
+where
+    K: Into<String>,
+{
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let mut data = Vec::with_capacity(limit);
+    let mut last_key = None;
+    for item in items.by_ref().take(limit) {
+        let (key, value) = item?;
+        last_key = Some(key);
+        data.push(value);
+    }
+
+    let next_start_after = match items.next() {
+        Some(_) => last_key.map(Into::into),
+        None => None,
+    };
+
+    Ok(PaginationResponse {
+        data,
+        next_start_after,
+        total_count,
+    })
+}
 
 pub fn query_all_asset_params(
     deps: Deps,
     start_after: Option<String>,
     limit: Option<u32>,
-) -> StdResult<Vec<AssetParams>> {
-    let start = start_after.as_ref().map(|denom| Bound::exclusive(denom.as_str()));
-    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
-    ASSET_PARAMS
-        .range(deps.storage, start, None, Order::Ascending)
-        .take(limit)
-        .map(|res| Ok(res?.1))
+    filter: Option<AssetParamsFilter>,
+) -> StdResult<PaginationResponse<AssetParams>> {
+    let params = asset_params();
+    let counts = ASSET_PARAMS_COUNTS.may_load(deps.storage)?.unwrap_or_default();
+
+    let (index, total_count) = match filter {
+        Some(AssetParamsFilter::CmWhitelisted) => {
+            (&params.idx.cm_whitelisted, counts.cm_whitelisted)
+        }
+        Some(AssetParamsFilter::BorrowEnabled) => {
+            (&params.idx.borrow_enabled, counts.borrow_enabled)
+        }
+        Some(AssetParamsFilter::HlsEnabled) => (&params.idx.hls_enabled, counts.hls_enabled),
+        None => {
+            let start = start_after.as_ref().map(|denom| Bound::exclusive(denom.as_str()));
+            let items = params.range(deps.storage, start, None, Order::Ascending);
+            return paginate(items, limit, counts.total);
+        }
+    };
+
+    let prefix = index.prefix(INDEX_TRUE);
+    let start = start_after.map(Bound::exclusive);
+    let items = prefix.range(deps.storage, start, None, Order::Ascending);
+    paginate(items, limit, total_count)
+}
+
+pub fn query_asset_params_batch(
+    deps: Deps,
+    denoms: Vec<String>,
+) -> StdResult<AssetParamsBatchResponse> {
+    denoms
+        .into_iter()
+        .map(|denom| {
+            let params = asset_params().may_load(deps.storage, &denom)?;
+            Ok((denom, params))
+        })
         .collect()
 }
 
 pub fn query_vault_config(deps: Deps, unchecked: &str) -> StdResult<VaultConfig> {
     let addr = deps.api.addr_validate(unchecked)?;
-    VAULT_CONFIGS.load(deps.storage, &addr)
+    vault_configs().load(deps.storage, &addr)
+}
+
+pub fn query_vault_configs_batch(
+    deps: Deps,
+    addresses: Vec<String>,
+) -> StdResult<VaultConfigsBatchResponse> {
+    addresses
+        .into_iter()
+        .map(|unchecked| {
+            let config = match deps.api.addr_validate(&unchecked) {
+                Ok(addr) => vault_configs().may_load(deps.storage, &addr)?,
+                Err(_) => None,
+            };
+            Ok((unchecked, config))
+        })
+        .collect()
 }
 
 pub fn query_all_vault_configs(
     deps: Deps,
     start_after: Option<String>,
     limit: Option<u32>,
-) -> StdResult<Vec<VaultConfig>> {
-    let vault_addr: Addr;
-    let start = match &start_after {
-        Some(unchecked) => {
-            vault_addr = deps.api.addr_validate(unchecked)?;
-            Some(Bound::exclusive(&vault_addr))
+    filter: Option<VaultConfigsFilter>,
+) -> StdResult<PaginationResponse<VaultConfig>> {
+    let start_after =
+        start_after.map(|unchecked| deps.api.addr_validate(&unchecked)).transpose()?;
+    let configs = vault_configs();
+    let counts = VAULT_CONFIGS_COUNTS.may_load(deps.storage)?.unwrap_or_default();
+
+    let (index, total_count) = match filter {
+        Some(VaultConfigsFilter::Whitelisted) => (&configs.idx.whitelisted, counts.whitelisted),
+        Some(VaultConfigsFilter::NonZeroDepositCap) => {
+            (&configs.idx.has_deposit_cap, counts.has_deposit_cap)
+        }
+        None => {
+            let start = start_after.as_ref().map(Bound::exclusive);
+            let items = configs.range(deps.storage, start, None, Order::Ascending);
+            return paginate(items, limit, counts.total);
         }
-        None => None,
     };
 
-    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+    let prefix = index.prefix(INDEX_TRUE);
+    let start = start_after.map(Bound::exclusive);
+    let items = prefix.range(deps.storage, start, None, Order::Ascending);
+    paginate(items, limit, total_count)
+}
+
+pub fn query_all_asset_categories(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<PaginationResponse<AssetCategory>> {
+    let total_count = ASSET_CATEGORIES_COUNT.may_load(deps.storage)?.unwrap_or_default();
+    let start = start_after.as_ref().map(|name| Bound::exclusive(name.as_str()));
+    let items = ASSET_CATEGORIES.range(deps.storage, start, None, Order::Ascending);
+    paginate(items, limit, total_count)
+}
 
-    VAULT_CONFIGS
-        .range(deps.storage, start, None, Order::Ascending)
-        .take(limit)
-        .map(|res| Ok(res?.1))
-        .collect()
+pub fn query_isolation_check(
+    deps: Deps,
+    collateral_denom: &str,
+    debt_denom: &str,
+) -> StdResult<IsolationCheckResponse> {
+    let params = asset_params().load(deps.storage, collateral_denom)?;
+    let res = match params.isolation {
+        Some(isolation) => IsolationCheckResponse {
+            allowed: isolation.is_debt_allowed(debt_denom),
+            debt_ceiling: Some(isolation.debt_ceiling),
+        },
+        None => IsolationCheckResponse {
+            allowed: true,
+            debt_ceiling: None,
+        },
+    };
+    Ok(res)
 }
@@ -1,10 +1,227 @@
-use cosmwasm_std::{Addr, Decimal};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Empty, StdResult, Storage};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use mars_owner::Owner;
 
-use crate::types::{asset::AssetParams, vault::VaultConfig};
+use crate::types::{
+    asset::AssetParams, category::AssetCategory, hls::HlsParams, vault::VaultConfig,
+};
 
 pub const OWNER: Owner = Owner::new("owner");
-pub const ASSET_PARAMS: Map<&str, AssetParams> = Map::new("asset_params");
-pub const VAULT_CONFIGS: Map<&Addr, VaultConfig> = Map::new("vault_configs");
+pub const ASSET_CATEGORIES: Map<&str, AssetCategory> = Map::new("asset_categories");
 pub const TARGET_HEALTH_FACTOR: Item<Decimal> = Item::new("target_health_factor");
+
+/// Entry counts, kept up to date on every save and removal so paginated queries can return a total
+/// count without iterating storage
+pub const ASSET_PARAMS_COUNTS: Item<AssetParamsCounts> = Item::new("asset_params_counts");
+pub const VAULT_CONFIGS_COUNTS: Item<VaultConfigsCounts> = Item::new("vault_configs_counts");
+pub const ASSET_CATEGORIES_COUNT: Item<u64> = Item::new("asset_categories_count");
+
+/// Asset params and vault configs referencing an asset category in their HLS correlations, keyed
+/// by the category name and the asset's denom or the vault's address
+pub const CATEGORY_REFERENCES: Map<(&str, &str), Empty> = Map::new("category_references");
+
+/// Index key of entries matching a boolean index (`cw-storage-plus` can't use `bool` as a key)
+pub const INDEX_TRUE: u8 = 1;
+
+pub struct AssetParamsIndexes<'a> {
+    pub cm_whitelisted: MultiIndex<'a, u8, AssetParams, String>,
+    pub borrow_enabled: MultiIndex<'a, u8, AssetParams, String>,
+    pub hls_enabled: MultiIndex<'a, u8, AssetParams, String>,
+}
+
+impl<'a> IndexList<AssetParams> for AssetParamsIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<AssetParams>> + '_> {
+        let v: Vec<&dyn Index<AssetParams>> =
+            vec![&self.cm_whitelisted, &self.borrow_enabled, &self.hls_enabled];
+        Box::new(v.into_iter())
+    }
+}
+
+fn cm_whitelisted_key(params: &AssetParams) -> u8 {
+    u8::from(params.credit_manager.whitelisted)
+}
+
+fn borrow_enabled_key(params: &AssetParams) -> u8 {
+    u8::from(params.red_bank.borrow_enabled)
+}
+
+fn hls_enabled_key(params: &AssetParams) -> u8 {
+    u8::from(params.credit_manager.hls.is_some())
+}
+
+pub fn asset_params<'a>() -> IndexedMap<'a, &'a str, AssetParams, AssetParamsIndexes<'a>> {
+    let indexes = AssetParamsIndexes {
+        cm_whitelisted: MultiIndex::new(
+            |_, p| cm_whitelisted_key(p),
+            "asset_params",
+            "asset_params__cm_whitelisted",
+        ),
+        borrow_enabled: MultiIndex::new(
+            |_, p| borrow_enabled_key(p),
+            "asset_params",
+            "asset_params__borrow_enabled",
+        ),
+        hls_enabled: MultiIndex::new(
+            |_, p| hls_enabled_key(p),
+            "asset_params",
+            "asset_params__hls_enabled",
+        ),
+    };
+    IndexedMap::new("asset_params", indexes)
+}
+
+/// Number of asset params in total and matching each index
+#[cw_serde]
+#[derive(Default)]
+pub struct AssetParamsCounts {
+    pub total: u64,
+    pub cm_whitelisted: u64,
+    pub borrow_enabled: u64,
+    pub hls_enabled: u64,
+}
+
+impl AssetParamsCounts {
+    pub fn add(&mut self, params: &AssetParams) {
+        self.total += 1;
+        self.cm_whitelisted += u64::from(cm_whitelisted_key(params));
+        self.borrow_enabled += u64::from(borrow_enabled_key(params));
+        self.hls_enabled += u64::from(hls_enabled_key(params));
+    }
+
+    pub fn sub(&mut self, params: &AssetParams) {
+        self.total = self.total.saturating_sub(1);
+        self.cm_whitelisted = self.cm_whitelisted.saturating_sub(cm_whitelisted_key(params).into());
+        self.borrow_enabled = self.borrow_enabled.saturating_sub(borrow_enabled_key(params).into());
+        self.hls_enabled = self.hls_enabled.saturating_sub(hls_enabled_key(params).into());
+    }
+}
+
+/// Save asset params, keeping `ASSET_PARAMS_COUNTS` up to date
+pub fn save_asset_params(storage: &mut dyn Storage, params: &AssetParams) -> StdResult<()> {
+    let old = asset_params().may_load(storage, &params.denom)?;
+
+    let mut counts = ASSET_PARAMS_COUNTS.may_load(storage)?.unwrap_or_default();
+    if let Some(old) = &old {
+        counts.sub(old);
+        remove_category_references(storage, &params.denom, &old.credit_manager.hls);
+    }
+    counts.add(params);
+    ASSET_PARAMS_COUNTS.save(storage, &counts)?;
+    save_category_references(storage, &params.denom, &params.credit_manager.hls)?;
+
+    asset_params().save(storage, &params.denom, params)
+}
+
+pub struct VaultConfigIndexes<'a> {
+    pub whitelisted: MultiIndex<'a, u8, VaultConfig, Addr>,
+    pub has_deposit_cap: MultiIndex<'a, u8, VaultConfig, Addr>,
+}
+
+impl<'a> IndexList<VaultConfig> for VaultConfigIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<VaultConfig>> + '_> {
+        let v: Vec<&dyn Index<VaultConfig>> = vec![&self.whitelisted, &self.has_deposit_cap];
+        Box::new(v.into_iter())
+    }
+}
+
+fn whitelisted_key(config: &VaultConfig) -> u8 {
+    u8::from(config.whitelisted)
+}
+
+fn has_deposit_cap_key(config: &VaultConfig) -> u8 {
+    u8::from(!config.deposit_cap.amount.is_zero())
+}
+
+pub fn vault_configs<'a>() -> IndexedMap<'a, &'a Addr, VaultConfig, VaultConfigIndexes<'a>> {
+    let indexes = VaultConfigIndexes {
+        whitelisted: MultiIndex::new(
+            |_, c| whitelisted_key(c),
+            "vault_configs",
+            "vault_configs__whitelisted",
+        ),
+        has_deposit_cap: MultiIndex::new(
+            |_, c| has_deposit_cap_key(c),
+            "vault_configs",
+            "vault_configs__has_deposit_cap",
+        ),
+    };
+    IndexedMap::new("vault_configs", indexes)
+}
+
+/// Number of vault configs in total and matching each index
+#[cw_serde]
+#[derive(Default)]
+pub struct VaultConfigsCounts {
+    pub total: u64,
+    pub whitelisted: u64,
+    pub has_deposit_cap: u64,
+}
+
+impl VaultConfigsCounts {
+    pub fn add(&mut self, config: &VaultConfig) {
+        self.total += 1;
+        self.whitelisted += u64::from(whitelisted_key(config));
+        self.has_deposit_cap += u64::from(has_deposit_cap_key(config));
+    }
+
+    pub fn sub(&mut self, config: &VaultConfig) {
+        self.total = self.total.saturating_sub(1);
+        self.whitelisted = self.whitelisted.saturating_sub(whitelisted_key(config).into());
+        self.has_deposit_cap =
+            self.has_deposit_cap.saturating_sub(has_deposit_cap_key(config).into());
+    }
+}
+
+/// Save a vault config, keeping `VAULT_CONFIGS_COUNTS` up to date
+pub fn save_vault_config(storage: &mut dyn Storage, config: &VaultConfig) -> StdResult<()> {
+    let old = vault_configs().may_load(storage, &config.addr)?;
+
+    let mut counts = VAULT_CONFIGS_COUNTS.may_load(storage)?.unwrap_or_default();
+    if let Some(old) = &old {
+        counts.sub(old);
+        remove_category_references(storage, config.addr.as_str(), &old.hls);
+    }
+    counts.add(config);
+    VAULT_CONFIGS_COUNTS.save(storage, &counts)?;
+    save_category_references(storage, config.addr.as_str(), &config.hls)?;
+
+    vault_configs().save(storage, &config.addr, config)
+}
+
+/// Record `referrer` as referencing the categories in its HLS correlations
+pub fn save_category_references(
+    storage: &mut dyn Storage,
+    referrer: &str,
+    hls: &Option<HlsParams>,
+) -> StdResult<()> {
+    for name in hls.iter().flat_map(|hls| hls.categories()) {
+        CATEGORY_REFERENCES.save(storage, (name, referrer), &Empty {})?;
+    }
+    Ok(())
+}
+
+fn remove_category_references(storage: &mut dyn Storage, referrer: &str, hls: &Option<HlsParams>) {
+    for name in hls.iter().flat_map(|hls| hls.categories()) {
+        CATEGORY_REFERENCES.remove(storage, (name, referrer));
+    }
+}
+
+/// Save an asset category, keeping `ASSET_CATEGORIES_COUNT` up to date
+pub fn save_asset_category(storage: &mut dyn Storage, category: &AssetCategory) -> StdResult<()> {
+    if !ASSET_CATEGORIES.has(storage, &category.name) {
+        let count = ASSET_CATEGORIES_COUNT.may_load(storage)?.unwrap_or_default();
+        ASSET_CATEGORIES_COUNT.save(storage, &(count + 1))?;
+    }
+    ASSET_CATEGORIES.save(storage, &category.name, category)
+}
+
+/// Remove an asset category, keeping `ASSET_CATEGORIES_COUNT` up to date
+pub fn remove_asset_category(storage: &mut dyn Storage, name: &str) -> StdResult<()> {
+    if ASSET_CATEGORIES.has(storage, name) {
+        let count = ASSET_CATEGORIES_COUNT.may_load(storage)?.unwrap_or_default();
+        ASSET_CATEGORIES_COUNT.save(storage, &count.saturating_sub(1))?;
+        ASSET_CATEGORIES.remove(storage, name);
+    }
+    Ok(())
+}
@@ -6,15 +6,31 @@ use mars_utils::{
 };
 
 use crate::{
-    error::ContractResult,
-    execute::{assert_hls_lqt_gt_max_ltv, assert_lqt_gt_max_ltv},
-    types::hls::HlsParamsBase,
+    error::{ContractError, ContractResult},
+    execute::{
+        assert_borrow_cap_le_deposit_cap, assert_hls_lqt_gt_max_ltv, assert_hls_lqt_gte_lqt,
+        assert_hls_max_ltv_gte_max_ltv, assert_lqt_gt_max_ltv, assert_lqt_with_max_lb_lt_one,
+    },
+    types::{
+        hls::HlsParamsBase, interest_rate_model::InterestRateModel, price_source::PriceSource,
+    },
 };
 
 #[cw_serde]
 pub struct CmSettings<T> {
     pub whitelisted: bool,
     pub hls: Option<HlsParamsBase<T>>,
+    /// Max amount of the asset that can be held across all Credit Manager accounts.
+    /// Zero means the asset can't be held at all, so it must be non-zero for whitelisted assets.
+    /// Unlimited for params stored before it was introduced.
+    #[serde(default = "unlimited_exposure_cap")]
+    pub exposure_cap: Uint128,
+    /// Max share of a single account's total value that can be held in this asset
+    pub max_concentration: Option<Decimal>,
+}
+
+fn unlimited_exposure_cap() -> Uint128 {
+    Uint128::MAX
 }
 
 #[cw_serde]
@@ -22,6 +38,55 @@ pub struct RedBankSettings {
     pub deposit_enabled: bool,
     pub borrow_enabled: bool,
     pub deposit_cap: Uint128,
+    /// Max amount of the asset that can be borrowed from the Red Bank.
+    /// Zero for params stored before it was introduced, until migration sets it to the deposit cap.
+    #[serde(default)]
+    pub borrow_cap: Uint128,
+    /// Not set for params stored before it was introduced
+    pub interest_rate_model: Option<InterestRateModel>,
+    /// Percentage of borrowed interest that stays in the protocol as fees.
+    /// Defaults to zero for params stored before it was introduced; set through `MigrateMsg`.
+    #[serde(default)]
+    pub reserve_factor: Decimal,
+}
+
+/// Isolated assets can only be used as collateral for a restricted set of debt denoms, up to a
+/// ceiling on the total debt they back
+#[cw_serde]
+pub struct IsolationSettings {
+    /// Max total debt that can be backed by this asset as collateral
+    pub debt_ceiling: Uint128,
+    /// Debt denoms that can be borrowed against this asset
+    pub allowed_debt_denoms: Vec<String>,
+}
+
+impl IsolationSettings {
+    pub fn validate(&self, denom: &str) -> Result<(), ValidationError> {
+        for (i, debt_denom) in self.allowed_debt_denoms.iter().enumerate() {
+            validate_native_denom(debt_denom)?;
+
+            if debt_denom == denom {
+                return Err(ValidationError::InvalidParam {
+                    param_name: "allowed_debt_denoms".to_string(),
+                    invalid_value: debt_denom.clone(),
+                    predicate: format!("!= {denom} (asset denom)"),
+                });
+            }
+
+            if self.allowed_debt_denoms[..i].contains(debt_denom) {
+                return Err(ValidationError::InvalidParam {
+                    param_name: "allowed_debt_denoms".to_string(),
+                    invalid_value: debt_denom.clone(),
+                    predicate: "unique".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_debt_allowed(&self, debt_denom: &str) -> bool {
+        self.allowed_debt_denoms.iter().any(|d| d == debt_denom)
+    }
 }
 
 /// The LB will depend on the Health Factor and a couple other parameters as follows:
@@ -122,6 +187,15 @@ pub struct AssetParamsBase<T> {
     pub liquidation_threshold: Decimal,
     pub liquidation_bonus: LiquidationBonus,
     pub protocol_liquidation_fee: Decimal,
+    /// Max share of the debt that can be repaid in a single liquidation. The whole debt if not set.
+    pub close_factor: Option<Decimal>,
+    /// Positions whose debt value (or remaining debt value after liquidation) is below this can be
+    /// fully liquidated regardless of the close factor, so no dust is left behind
+    pub min_liquidation_value: Option<Uint128>,
+    /// How the oracle should price this asset. Optional until every asset has been migrated.
+    pub price_source: Option<PriceSource>,
+    /// If set, the asset is in isolation mode
+    pub isolation: Option<IsolationSettings>,
 }
 
 pub type AssetParams = AssetParamsBase<Addr>;
@@ -134,12 +208,18 @@ impl From<AssetParams> for AssetParamsUnchecked {
             credit_manager: CmSettings {
                 whitelisted: p.credit_manager.whitelisted,
                 hls: p.credit_manager.hls.map(Into::into),
+                exposure_cap: p.credit_manager.exposure_cap,
+                max_concentration: p.credit_manager.max_concentration,
             },
             red_bank: p.red_bank,
             max_loan_to_value: p.max_loan_to_value,
             liquidation_threshold: p.liquidation_threshold,
             liquidation_bonus: p.liquidation_bonus,
             protocol_liquidation_fee: p.protocol_liquidation_fee,
+            close_factor: p.close_factor,
+            min_liquidation_value: p.min_liquidation_value,
+            price_source: p.price_source,
+            isolation: p.isolation,
         }
     }
 }
@@ -153,12 +233,51 @@ impl AssetParamsUnchecked {
         assert_lqt_gt_max_ltv(self.max_loan_to_value, self.liquidation_threshold)?;
 
         self.liquidation_bonus.validate()?;
+        assert_lqt_with_max_lb_lt_one(
+            self.liquidation_threshold,
+            self.liquidation_bonus.max_lb,
+            "liquidation_threshold",
+        )?;
         decimal_param_lt_one(self.protocol_liquidation_fee, "protocol_liquidation_fee")?;
 
+        if let Some(close_factor) = self.close_factor {
+            assert_close_factor_within_range(close_factor)?;
+        }
+
+        if let Some(interest_rate_model) = self.red_bank.interest_rate_model.as_ref() {
+            interest_rate_model.validate()?;
+        }
+        decimal_param_lt_one(self.red_bank.reserve_factor, "reserve_factor")?;
+        assert_borrow_cap_le_deposit_cap(self.red_bank.deposit_cap, self.red_bank.borrow_cap)?;
+
         if let Some(hls) = self.credit_manager.hls.as_ref() {
             decimal_param_lt_one(hls.max_loan_to_value, "hls_max_loan_to_value")?;
             decimal_param_le_one(hls.liquidation_threshold, "hls_liquidation_threshold")?;
             assert_hls_lqt_gt_max_ltv(hls.max_loan_to_value, hls.liquidation_threshold)?;
+            assert_hls_max_ltv_gte_max_ltv(self.max_loan_to_value, hls.max_loan_to_value)?;
+            assert_hls_lqt_gte_lqt(self.liquidation_threshold, hls.liquidation_threshold)?;
+            assert_lqt_with_max_lb_lt_one(
+                hls.liquidation_threshold,
+                self.liquidation_bonus.max_lb,
+                "hls_liquidation_threshold",
+            )?;
+        }
+
+        assert_whitelisted_exposure_cap_gt_zero(
+            self.credit_manager.whitelisted,
+            self.credit_manager.exposure_cap,
+        )?;
+
+        if let Some(max_concentration) = self.credit_manager.max_concentration {
+            assert_max_concentration_within_range(max_concentration)?;
+        }
+
+        if let Some(price_source) = self.price_source.as_ref() {
+            price_source.validate(&self.denom)?;
+        }
+
+        if let Some(isolation) = self.isolation.as_ref() {
+            isolation.validate(&self.denom)?;
         }
 
         let hls = self.credit_manager.hls.as_ref().map(|hls| hls.check(api)).transpose()?;
@@ -168,12 +287,112 @@ impl AssetParamsUnchecked {
             credit_manager: CmSettings {
                 whitelisted: self.credit_manager.whitelisted,
                 hls,
+                exposure_cap: self.credit_manager.exposure_cap,
+                max_concentration: self.credit_manager.max_concentration,
             },
             red_bank: self.red_bank.clone(),
             max_loan_to_value: self.max_loan_to_value,
             liquidation_threshold: self.liquidation_threshold,
             liquidation_bonus: self.liquidation_bonus.clone(),
             protocol_liquidation_fee: self.protocol_liquidation_fee,
+            close_factor: self.close_factor,
+            min_liquidation_value: self.min_liquidation_value,
+            price_source: self.price_source.clone(),
+            isolation: self.isolation.clone(),
         })
     }
 }
+
+impl AssetParams {
+    /// Check a proposed Credit Manager position against the asset's exposure cap and max
+    /// concentration:
+    /// - `total_amount`: amount of the asset held across all Credit Manager accounts after the action
+    /// - `asset_value`: value of the asset held by the account after the action
+    /// - `account_value`: total value of the account after the action, in the same unit as `asset_value`
+    pub fn assert_cm_limits(
+        &self,
+        total_amount: Uint128,
+        asset_value: Uint128,
+        account_value: Uint128,
+    ) -> ContractResult<()> {
+        if total_amount > self.credit_manager.exposure_cap {
+            return Err(ContractError::ExposureCapExceeded {
+                denom: self.denom.clone(),
+                cap: self.credit_manager.exposure_cap,
+                amount: total_amount,
+            });
+        }
+
+        if let Some(max_concentration) = self.credit_manager.max_concentration {
+            if account_value.is_zero() {
+                return Ok(());
+            }
+            let concentration = Decimal::checked_from_ratio(asset_value, account_value)?;
+            if concentration > max_concentration {
+                return Err(ContractError::ConcentrationLimitExceeded {
+                    denom: self.denom.clone(),
+                    max_concentration,
+                    concentration,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Max debt value that can be repaid in a single liquidation, given the current debt value:
+    /// - `close_factor` of the debt, or the whole debt if not set
+    /// - the whole debt if it, or what would remain of it, is below `min_liquidation_value`
+    pub fn max_liquidatable_value(&self, debt_value: Uint128) -> Uint128 {
+        let close_factor = match self.close_factor {
+            Some(close_factor) => close_factor,
+            None => return debt_value,
+        };
+
+        let max_value = close_factor * debt_value;
+        let min_value = self.min_liquidation_value.unwrap_or_default();
+        if debt_value <= min_value || debt_value - max_value < min_value {
+            return debt_value;
+        }
+
+        max_value
+    }
+}
+
+fn assert_close_factor_within_range(close_factor: Decimal) -> Result<(), ValidationError> {
+    if close_factor.is_zero() || close_factor > Decimal::one() {
+        return Err(ValidationError::InvalidParam {
+            param_name: "close_factor".to_string(),
+            invalid_value: close_factor.to_string(),
+            predicate: "(0, 1]".to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn assert_whitelisted_exposure_cap_gt_zero(
+    whitelisted: bool,
+    exposure_cap: Uint128,
+) -> Result<(), ValidationError> {
+    if whitelisted && exposure_cap.is_zero() {
+        return Err(ValidationError::InvalidParam {
+            param_name: "exposure_cap".to_string(),
+            invalid_value: exposure_cap.to_string(),
+            predicate: "> 0 (whitelisted asset)".to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn assert_max_concentration_within_range(
+    max_concentration: Decimal,
+) -> Result<(), ValidationError> {
+    if max_concentration.is_zero() || max_concentration > Decimal::one() {
+        return Err(ValidationError::InvalidParam {
+            param_name: "max_concentration".to_string(),
+            invalid_value: max_concentration.to_string(),
+            predicate: "(0, 1]".to_string(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+use mars_utils::{
+    error::ValidationError,
+    helpers::{decimal_param_le_one, decimal_param_lt_one, validate_native_denom},
+};
+
+use crate::{
+    execute::{assert_lqt_gt_max_ltv, assert_lqt_with_max_lb_lt_one},
+    types::asset::LiquidationBonus,
+};
+
+pub const MAX_CATEGORY_NAME_LENGTH: usize = 64;
+
+/// A named group of correlated assets (e.g. all stATOM variants) sharing risk parameters that
+/// override the standard ones when assets of the same category are used against each other.
+/// Can be referenced from HLS correlations instead of listing every denom.
+#[cw_serde]
+pub struct AssetCategory {
+    pub name: String,
+    pub denoms: Vec<String>,
+    pub max_loan_to_value: Decimal,
+    pub liquidation_threshold: Decimal,
+    /// Falls back to the liquidation bonus of the collateral asset if not set
+    pub liquidation_bonus: Option<LiquidationBonus>,
+}
+
+impl AssetCategory {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_category_name(&self.name)?;
+
+        for (i, denom) in self.denoms.iter().enumerate() {
+            validate_native_denom(denom)?;
+
+            if self.denoms[..i].contains(denom) {
+                return Err(ValidationError::InvalidParam {
+                    param_name: "denoms".to_string(),
+                    invalid_value: denom.clone(),
+                    predicate: "unique".to_string(),
+                });
+            }
+        }
+
+        decimal_param_lt_one(self.max_loan_to_value, "max_loan_to_value")?;
+        decimal_param_le_one(self.liquidation_threshold, "liquidation_threshold")?;
+        assert_lqt_gt_max_ltv(self.max_loan_to_value, self.liquidation_threshold)?;
+
+        if let Some(lb) = self.liquidation_bonus.as_ref() {
+            lb.validate()?;
+            assert_lqt_with_max_lb_lt_one(
+                self.liquidation_threshold,
+                lb.max_lb,
+                "liquidation_threshold",
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn validate_category_name(name: &str) -> Result<(), ValidationError> {
+    let valid_chars = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if name.is_empty() || name.len() > MAX_CATEGORY_NAME_LENGTH || !valid_chars {
+        return Err(ValidationError::InvalidParam {
+            param_name: "name".to_string(),
+            invalid_value: name.to_string(),
+            predicate: format!(
+                "1 to {MAX_CATEGORY_NAME_LENGTH} alphanumeric, '_' or '-' characters"
+            ),
+        });
+    }
+    Ok(())
+}
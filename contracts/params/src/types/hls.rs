@@ -2,7 +2,7 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Api, Decimal};
 use mars_utils::helpers::validate_native_denom;
 
-use crate::error::ContractResult;
+use crate::{error::ContractResult, types::category::validate_category_name};
 
 #[cw_serde]
 pub enum HlsAssetType<T> {
@@ -12,6 +12,10 @@ pub enum HlsAssetType<T> {
     Vault {
         addr: T,
     },
+    /// All denoms of the named asset category
+    Category {
+        name: String,
+    },
 }
 
 impl From<HlsAssetType<Addr>> for HlsAssetType<String> {
@@ -27,6 +31,11 @@ impl From<HlsAssetType<Addr>> for HlsAssetType<String> {
             } => HlsAssetType::Vault {
                 addr: addr.to_string(),
             },
+            HlsAssetType::Category {
+                name,
+            } => HlsAssetType::Category {
+                name,
+            },
         }
     }
 }
@@ -75,8 +84,28 @@ impl HlsParamsUnchecked {
                     } => Ok(HlsAssetType::Vault {
                         addr: api.addr_validate(addr)?,
                     }),
+                    HlsAssetType::Category {
+                        name,
+                    } => {
+                        validate_category_name(name)?;
+                        Ok(HlsAssetType::Category {
+                            name: name.clone(),
+                        })
+                    }
                 })
                 .collect::<ContractResult<Vec<_>>>()?,
         })
     }
 }
+
+impl<T> HlsParamsBase<T> {
+    /// Names of the asset categories referenced by the correlations
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.correlations.iter().filter_map(|c| match c {
+            HlsAssetType::Category {
+                name,
+            } => Some(name.as_str()),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,73 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+use mars_utils::{error::ValidationError, helpers::decimal_param_le_one};
+
+use crate::error::ContractResult;
+
+/// Borrow rate is a piecewise linear function of the utilization rate U:
+/// - U <= U_optimal: base + slope_1 * U / U_optimal
+/// - U > U_optimal:  base + slope_1 + slope_2 * (U - U_optimal) / (1 - U_optimal)
+#[cw_serde]
+pub struct InterestRateModel {
+    /// Utilization rate at which the second slope kicks in
+    pub optimal_utilization_rate: Decimal,
+    /// Borrow rate at zero utilization
+    pub base: Decimal,
+    /// Slope of the borrow rate when utilization is at or below the optimal utilization rate
+    pub slope_1: Decimal,
+    /// Slope of the borrow rate when utilization is above the optimal utilization rate
+    pub slope_2: Decimal,
+}
+
+impl InterestRateModel {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        decimal_param_le_one(self.optimal_utilization_rate, "optimal_utilization_rate")?;
+        if self.optimal_utilization_rate.is_zero() {
+            return Err(ValidationError::InvalidParam {
+                param_name: "optimal_utilization_rate".to_string(),
+                invalid_value: self.optimal_utilization_rate.to_string(),
+                predicate: "> 0".to_string(),
+            });
+        }
+
+        if self.slope_2 < self.slope_1 {
+            return Err(ValidationError::InvalidParam {
+                param_name: "slope_2".to_string(),
+                invalid_value: self.slope_2.to_string(),
+                predicate: format!(">= {} (slope_1)", self.slope_1),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Borrow rate at the given utilization rate
+    pub fn get_borrow_rate(&self, utilization_rate: Decimal) -> ContractResult<Decimal> {
+        let rate = if utilization_rate <= self.optimal_utilization_rate {
+            let slope_1_part = self
+                .slope_1
+                .checked_mul(utilization_rate.checked_div(self.optimal_utilization_rate)?)?;
+            self.base.checked_add(slope_1_part)?
+        } else {
+            let excess_utilization = (utilization_rate - self.optimal_utilization_rate)
+                .checked_div(Decimal::one() - self.optimal_utilization_rate)?;
+            let slope_2_part = self.slope_2.checked_mul(excess_utilization)?;
+            self.base.checked_add(self.slope_1)?.checked_add(slope_2_part)?
+        };
+        Ok(rate)
+    }
+
+    /// Supply (liquidity) rate given the borrow rate, the utilization rate and the share of
+    /// interest kept by the protocol
+    pub fn get_supply_rate(
+        &self,
+        borrow_rate: Decimal,
+        utilization_rate: Decimal,
+        reserve_factor: Decimal,
+    ) -> ContractResult<Decimal> {
+        let rate = borrow_rate
+            .checked_mul(utilization_rate)?
+            .checked_mul(Decimal::one().checked_sub(reserve_factor)?)?;
+        Ok(rate)
+    }
+}
@@ -1,3 +1,6 @@
 pub mod asset;
+pub mod category;
 pub mod hls;
+pub mod interest_rate_model;
+pub mod price_source;
 pub mod vault;
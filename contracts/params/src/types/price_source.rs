@@ -0,0 +1,115 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+use mars_utils::{error::ValidationError, helpers::validate_native_denom};
+
+/// Osmosis keeps TWAP records for 48 hours, so longer windows can't be queried
+pub const MAX_TWAP_WINDOW_SIZE: u64 = 172800;
+
+/// Describes how the price of an asset is determined by the oracle
+#[cw_serde]
+pub enum PriceSource {
+    /// Returns a fixed value
+    Fixed {
+        price: Decimal,
+    },
+    /// Osmosis spot price quoted in the oracle's base denom
+    Spot {
+        pool_id: u64,
+    },
+    /// Osmosis arithmetic TWAP quoted in the oracle's base denom
+    ArithmeticTwap {
+        pool_id: u64,
+        /// Window size in seconds representing the entire window for which the TWAP is calculated
+        window_size: u64,
+    },
+    /// Osmosis geometric TWAP quoted in the oracle's base denom
+    GeometricTwap {
+        pool_id: u64,
+        /// Window size in seconds representing the entire window for which the TWAP is calculated
+        window_size: u64,
+    },
+    /// Staked derivative (e.g. stATOM) priced as the redemption rate times the price of the
+    /// underlying (transitive) denom
+    StakedDerivative {
+        /// Underlying denom the derivative can be redeemed for
+        transitive_denom: String,
+        /// Maximum age in seconds of the redemption rate before the price is considered invalid
+        max_staleness: u64,
+    },
+}
+
+impl PriceSource {
+    pub fn validate(&self, denom: &str) -> Result<(), ValidationError> {
+        match self {
+            PriceSource::Fixed {
+                price,
+            } => {
+                if price.is_zero() {
+                    return Err(ValidationError::InvalidParam {
+                        param_name: "price".to_string(),
+                        invalid_value: price.to_string(),
+                        predicate: "> 0".to_string(),
+                    });
+                }
+                Ok(())
+            }
+            PriceSource::Spot {
+                pool_id,
+            } => assert_pool_id_gt_zero(*pool_id),
+            PriceSource::ArithmeticTwap {
+                pool_id,
+                window_size,
+            }
+            | PriceSource::GeometricTwap {
+                pool_id,
+                window_size,
+            } => {
+                assert_pool_id_gt_zero(*pool_id)?;
+                assert_twap_window_size_within_range(*window_size)
+            }
+            PriceSource::StakedDerivative {
+                transitive_denom,
+                max_staleness,
+            } => {
+                validate_native_denom(transitive_denom)?;
+                if transitive_denom == denom {
+                    return Err(ValidationError::InvalidParam {
+                        param_name: "transitive_denom".to_string(),
+                        invalid_value: transitive_denom.clone(),
+                        predicate: format!("!= {denom} (asset denom)"),
+                    });
+                }
+                if *max_staleness == 0 {
+                    return Err(ValidationError::InvalidParam {
+                        param_name: "max_staleness".to_string(),
+                        invalid_value: max_staleness.to_string(),
+                        predicate: "> 0".to_string(),
+                    });
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn assert_pool_id_gt_zero(pool_id: u64) -> Result<(), ValidationError> {
+    if pool_id == 0 {
+        return Err(ValidationError::InvalidParam {
+            param_name: "pool_id".to_string(),
+            invalid_value: pool_id.to_string(),
+            predicate: "> 0".to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn assert_twap_window_size_within_range(window_size: u64) -> Result<(), ValidationError> {
+    if window_size == 0 || window_size > MAX_TWAP_WINDOW_SIZE {
+        return Err(ValidationError::InvalidParam {
+            param_name: "window_size".to_string(),
+            invalid_value: window_size.to_string(),
+            predicate: format!("(0, {MAX_TWAP_WINDOW_SIZE}]"),
+        });
+    }
+    Ok(())
+}
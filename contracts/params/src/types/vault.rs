@@ -1,13 +1,43 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Api, Coin, Decimal};
-use mars_utils::helpers::decimal_param_le_one;
+use mars_utils::{
+    error::ValidationError,
+    helpers::{decimal_param_le_one, decimal_param_lt_one},
+};
 
 use crate::{
     error::ContractResult,
-    execute::{assert_hls_lqt_gt_max_ltv, assert_lqt_gt_max_ltv},
-    types::hls::HlsParamsBase,
+    execute::{
+        assert_hls_lqt_gt_max_ltv, assert_hls_lqt_gte_lqt, assert_hls_max_ltv_gte_max_ltv,
+        assert_lqt_gt_max_ltv, assert_lqt_with_max_lb_lt_one,
+    },
+    types::{asset::LiquidationBonus, hls::HlsParamsBase},
 };
 
+/// One year in seconds, longer lockups are most likely a unit mistake
+pub const MAX_LOCKUP_DURATION: u64 = 31_536_000;
+
+#[cw_serde]
+pub struct VaultLockup {
+    /// Time in seconds it takes to unlock a vault position
+    pub duration: u64,
+    /// Whether the vault allows positions to be unlocked immediately, e.g. during liquidations
+    pub force_unlock_supported: bool,
+}
+
+impl VaultLockup {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.duration == 0 || self.duration > MAX_LOCKUP_DURATION {
+            return Err(ValidationError::InvalidParam {
+                param_name: "lockup_duration".to_string(),
+                invalid_value: self.duration.to_string(),
+                predicate: format!("(0, {MAX_LOCKUP_DURATION}]"),
+            });
+        }
+        Ok(())
+    }
+}
+
 #[cw_serde]
 pub struct VaultConfigBase<T> {
     pub addr: T,
@@ -16,6 +46,14 @@ pub struct VaultConfigBase<T> {
     pub liquidation_threshold: Decimal,
     pub whitelisted: bool,
     pub hls: Option<HlsParamsBase<T>>,
+    /// If not set, liquidations of the vault's tokens use the liquidation bonus of the vault's
+    /// underlying asset
+    pub liquidation_bonus: Option<LiquidationBonus>,
+    /// If not set, liquidations of the vault's tokens use the protocol liquidation fee of the
+    /// vault's underlying asset
+    pub protocol_liquidation_fee: Option<Decimal>,
+    /// Not set if positions in the vault can be exited immediately
+    pub lockup: Option<VaultLockup>,
 }
 
 pub type VaultConfigUnchecked = VaultConfigBase<String>;
@@ -30,6 +68,9 @@ impl From<VaultConfig> for VaultConfigUnchecked {
             liquidation_threshold: v.liquidation_threshold,
             whitelisted: v.whitelisted,
             hls: v.hls.map(Into::into),
+            liquidation_bonus: v.liquidation_bonus,
+            protocol_liquidation_fee: v.protocol_liquidation_fee,
+            lockup: v.lockup,
         }
     }
 }
@@ -40,11 +81,37 @@ impl VaultConfigUnchecked {
         decimal_param_le_one(self.liquidation_threshold, "liquidation_threshold")?;
         assert_lqt_gt_max_ltv(self.max_loan_to_value, self.liquidation_threshold)?;
 
+        if let Some(lb) = self.liquidation_bonus.as_ref() {
+            lb.validate()?;
+            assert_lqt_with_max_lb_lt_one(
+                self.liquidation_threshold,
+                lb.max_lb,
+                "liquidation_threshold",
+            )?;
+        }
+
+        if let Some(fee) = self.protocol_liquidation_fee {
+            decimal_param_lt_one(fee, "protocol_liquidation_fee")?;
+        }
+
+        if let Some(lockup) = self.lockup.as_ref() {
+            lockup.validate()?;
+        }
+
         // High levered strategies
         if let Some(hls) = self.hls.as_ref() {
             decimal_param_le_one(hls.max_loan_to_value, "hls_max_loan_to_value")?;
             decimal_param_le_one(hls.liquidation_threshold, "hls_liquidation_threshold")?;
             assert_hls_lqt_gt_max_ltv(hls.max_loan_to_value, hls.liquidation_threshold)?;
+            assert_hls_max_ltv_gte_max_ltv(self.max_loan_to_value, hls.max_loan_to_value)?;
+            assert_hls_lqt_gte_lqt(self.liquidation_threshold, hls.liquidation_threshold)?;
+            if let Some(lb) = self.liquidation_bonus.as_ref() {
+                assert_lqt_with_max_lb_lt_one(
+                    hls.liquidation_threshold,
+                    lb.max_lb,
+                    "hls_liquidation_threshold",
+                )?;
+            }
         }
 
         Ok(VaultConfig {
@@ -54,6 +121,9 @@ impl VaultConfigUnchecked {
             liquidation_threshold: self.liquidation_threshold,
             whitelisted: self.whitelisted,
             hls: self.hls.as_ref().map(|hls| hls.check(api)).transpose()?,
+            liquidation_bonus: self.liquidation_bonus.clone(),
+            protocol_liquidation_fee: self.protocol_liquidation_fee,
+            lockup: self.lockup.clone(),
         })
     }
 }
@@ -6,6 +6,7 @@ pub fn mock_params_contract() -> Box<dyn Contract<Empty>> {
         mars_params::contract::execute,
         mars_params::contract::instantiate,
         mars_params::contract::query,
-    );
+    )
+    .with_migrate(mars_params::contract::migrate);
     Box::new(contract)
 }
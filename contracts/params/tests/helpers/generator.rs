@@ -3,6 +3,8 @@ use std::str::FromStr;
 use cosmwasm_std::{coin, Decimal, Uint128};
 use mars_params::types::{
     asset::{AssetParamsUnchecked, CmSettings, LiquidationBonus, RedBankSettings},
+    category::AssetCategory,
+    interest_rate_model::InterestRateModel,
     vault::VaultConfigUnchecked,
 };
 
@@ -12,11 +14,21 @@ pub fn default_asset_params(denom: &str) -> AssetParamsUnchecked {
         credit_manager: CmSettings {
             whitelisted: false,
             hls: None,
+            exposure_cap: Uint128::new(1_000_000_000),
+            max_concentration: None,
         },
         red_bank: RedBankSettings {
             deposit_enabled: true,
             borrow_enabled: false,
             deposit_cap: Uint128::new(1_000_000_000),
+            borrow_cap: Uint128::new(800_000_000),
+            interest_rate_model: Some(InterestRateModel {
+                optimal_utilization_rate: Decimal::percent(80),
+                base: Decimal::zero(),
+                slope_1: Decimal::percent(20),
+                slope_2: Decimal::percent(200),
+            }),
+            reserve_factor: Decimal::percent(10),
         },
         max_loan_to_value: Decimal::from_str("0.6").unwrap(),
         liquidation_threshold: Decimal::from_str("0.7").unwrap(),
@@ -27,6 +39,10 @@ pub fn default_asset_params(denom: &str) -> AssetParamsUnchecked {
             max_lb: Decimal::percent(8),
         },
         protocol_liquidation_fee: Decimal::percent(2),
+        close_factor: None,
+        min_liquidation_value: None,
+        price_source: None,
+        isolation: None,
     }
 }
 
@@ -38,5 +54,18 @@ pub fn default_vault_config(addr: &str) -> VaultConfigUnchecked {
         liquidation_threshold: Decimal::from_str("0.5").unwrap(),
         whitelisted: true,
         hls: None,
+        liquidation_bonus: None,
+        protocol_liquidation_fee: None,
+        lockup: None,
+    }
+}
+
+pub fn default_asset_category(name: &str) -> AssetCategory {
+    AssetCategory {
+        name: name.to_string(),
+        denoms: vec!["uatom".to_string(), "stuatom".to_string()],
+        max_loan_to_value: Decimal::from_str("0.85").unwrap(),
+        liquidation_threshold: Decimal::from_str("0.9").unwrap(),
+        liquidation_bonus: None,
     }
 }
@@ -1,14 +1,18 @@
 use std::{mem::take, str::FromStr};
 
 use anyhow::Result as AnyResult;
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{Addr, Decimal, Empty};
 use cw_multi_test::{App, AppResponse, BasicApp, Executor};
+use cw_storage_plus::Map;
 use mars_owner::{OwnerResponse, OwnerUpdate};
 use mars_params::{
     msg::{
-        AssetParamsUpdate, EmergencyUpdate, ExecuteMsg, InstantiateMsg, QueryMsg, VaultConfigUpdate,
+        AssetCategoryUpdate, AssetParamsBatchResponse, AssetParamsFilter, AssetParamsUpdate,
+        EmergencyUpdate, ExecuteMsg, InstantiateMsg, IsolationCheckResponse, MigrateMsg,
+        PaginationResponse, QueryMsg, VaultConfigUpdate, VaultConfigsBatchResponse,
+        VaultConfigsFilter,
     },
-    types::{asset::AssetParams, vault::VaultConfig},
+    types::{asset::AssetParams, category::AssetCategory, vault::VaultConfig},
 };
 
 use crate::helpers::mock_params_contract;
@@ -64,6 +68,19 @@ impl MockEnv {
         )
     }
 
+    pub fn update_asset_category(
+        &mut self,
+        sender: &Addr,
+        update: AssetCategoryUpdate,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.params_contract.clone(),
+            &ExecuteMsg::UpdateAssetCategory(update),
+            &[],
+        )
+    }
+
     pub fn update_owner(&mut self, sender: &Addr, update: OwnerUpdate) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             sender.clone(),
@@ -99,6 +116,17 @@ impl MockEnv {
         )
     }
 
+    pub fn migrate(&mut self, sender: &Addr, msg: MigrateMsg) -> AnyResult<AppResponse> {
+        let code_id = self.app.store_code(mock_params_contract());
+        self.app.migrate_contract(sender.clone(), self.params_contract.clone(), &msg, code_id)
+    }
+
+    /// Write asset params straight into storage, e.g. in the format of a previous contract version
+    pub fn save_raw_asset_params(&mut self, denom: &str, params: &str) {
+        let key = Map::<&str, Empty>::new("asset_params").key(denom);
+        self.app.contract_storage_mut(&self.params_contract).set(&key, params.as_bytes());
+    }
+
     //--------------------------------------------------------------------------------------------------
     // Queries
     //--------------------------------------------------------------------------------------------------
@@ -129,6 +157,54 @@ impl MockEnv {
         start_after: Option<String>,
         limit: Option<u32>,
     ) -> Vec<AssetParams> {
+        self.query_asset_params_page(start_after, limit).data
+    }
+
+    pub fn query_asset_params_page(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> PaginationResponse<AssetParams> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.params_contract.clone(),
+                &QueryMsg::AllAssetParams {
+                    start_after,
+                    limit,
+                    filter: None,
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_asset_params_batch(&self, denoms: &[&str]) -> AssetParamsBatchResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.params_contract.clone(),
+                &QueryMsg::AssetParamsBatch {
+                    denoms: denoms.iter().map(|d| d.to_string()).collect(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_all_asset_params_filtered(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        filter: AssetParamsFilter,
+    ) -> Vec<AssetParams> {
+        self.query_asset_params_filtered_page(start_after, limit, filter).data
+    }
+
+    pub fn query_asset_params_filtered_page(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        filter: AssetParamsFilter,
+    ) -> PaginationResponse<AssetParams> {
         self.app
             .wrap()
             .query_wasm_smart(
@@ -136,6 +212,7 @@ impl MockEnv {
                 &QueryMsg::AllAssetParams {
                     start_after,
                     limit,
+                    filter: Some(filter),
                 },
             )
             .unwrap()
@@ -153,11 +230,31 @@ impl MockEnv {
             .unwrap()
     }
 
+    pub fn query_vault_configs_batch(&self, addresses: &[&str]) -> VaultConfigsBatchResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.params_contract.clone(),
+                &QueryMsg::VaultConfigsBatch {
+                    addresses: addresses.iter().map(|a| a.to_string()).collect(),
+                },
+            )
+            .unwrap()
+    }
+
     pub fn query_all_vault_configs(
         &self,
         start_after: Option<String>,
         limit: Option<u32>,
     ) -> Vec<VaultConfig> {
+        self.query_vault_configs_page(start_after, limit).data
+    }
+
+    pub fn query_vault_configs_page(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> PaginationResponse<VaultConfig> {
         self.app
             .wrap()
             .query_wasm_smart(
@@ -165,6 +262,89 @@ impl MockEnv {
                 &QueryMsg::AllVaultConfigs {
                     start_after,
                     limit,
+                    filter: None,
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_all_vault_configs_filtered(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        filter: VaultConfigsFilter,
+    ) -> Vec<VaultConfig> {
+        self.query_vault_configs_filtered_page(start_after, limit, filter).data
+    }
+
+    pub fn query_vault_configs_filtered_page(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        filter: VaultConfigsFilter,
+    ) -> PaginationResponse<VaultConfig> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.params_contract.clone(),
+                &QueryMsg::AllVaultConfigs {
+                    start_after,
+                    limit,
+                    filter: Some(filter),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_asset_category(&self, name: &str) -> AssetCategory {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.params_contract.clone(),
+                &QueryMsg::AssetCategory {
+                    name: name.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_all_asset_categories(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Vec<AssetCategory> {
+        self.query_asset_categories_page(start_after, limit).data
+    }
+
+    pub fn query_asset_categories_page(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> PaginationResponse<AssetCategory> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.params_contract.clone(),
+                &QueryMsg::AllAssetCategories {
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_isolation_check(
+        &self,
+        collateral_denom: &str,
+        debt_denom: &str,
+    ) -> IsolationCheckResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.params_contract.clone(),
+                &QueryMsg::IsolationCheck {
+                    collateral_denom: collateral_denom.to_string(),
+                    debt_denom: debt_denom.to_string(),
                 },
             )
             .unwrap()
@@ -191,7 +371,7 @@ impl MockEnvBuilder {
             },
             &[],
             "mock-params-contract",
-            None,
+            Some("owner".to_string()),
         )?;
 
         if self.emergency_owner.is_some() {
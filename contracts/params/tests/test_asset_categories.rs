@@ -0,0 +1,326 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Decimal, StdError::NotFound};
+use mars_owner::OwnerError;
+use mars_params::{
+    error::ContractError::{AssetCategoryInUse, Owner, Std, Validation},
+    msg::{AssetCategoryUpdate, AssetParamsUpdate, VaultConfigUpdate},
+    types::hls::{HlsAssetType, HlsParamsUnchecked},
+};
+use mars_utils::error::ValidationError::InvalidParam;
+
+use crate::helpers::{
+    assert_contents_equal, assert_err, default_asset_category, default_asset_params,
+    default_vault_config, MockEnv,
+};
+
+pub mod helpers;
+
+fn hls_with_category(name: &str) -> HlsParamsUnchecked {
+    HlsParamsUnchecked {
+        max_loan_to_value: Decimal::from_str("0.8").unwrap(),
+        liquidation_threshold: Decimal::from_str("0.85").unwrap(),
+        correlations: vec![HlsAssetType::Category {
+            name: name.to_string(),
+        }],
+    }
+}
+
+#[test]
+fn initial_state_of_asset_categories() {
+    let mock = MockEnv::new().build().unwrap();
+    let categories = mock.query_all_asset_categories(None, None);
+    assert!(categories.is_empty());
+}
+
+#[test]
+fn only_owner_can_update_asset_categories() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let bad_guy = Addr::unchecked("doctor_otto_983");
+    let res = mock.update_asset_category(
+        &bad_guy,
+        AssetCategoryUpdate::AddOrUpdate {
+            category: default_asset_category("atom_lsds"),
+        },
+    );
+    assert_err(res, Owner(OwnerError::NotOwner {}));
+
+    let res = mock.update_asset_category(
+        &bad_guy,
+        AssetCategoryUpdate::Remove {
+            name: "atom_lsds".to_string(),
+        },
+    );
+    assert_err(res, Owner(OwnerError::NotOwner {}));
+}
+
+#[test]
+fn adding_and_updating_asset_category() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    let mut category = default_asset_category("atom_lsds");
+    mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::AddOrUpdate {
+            category: category.clone(),
+        },
+    )
+    .unwrap();
+    assert_eq!(category, mock.query_asset_category("atom_lsds"));
+
+    category.denoms.push("stkuatom".to_string());
+    mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::AddOrUpdate {
+            category: category.clone(),
+        },
+    )
+    .unwrap();
+    assert_eq!(category, mock.query_asset_category("atom_lsds"));
+
+    let categories = mock.query_all_asset_categories(None, None);
+    assert_eq!(1, categories.len());
+}
+
+#[test]
+fn asset_category_validation() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    let res = mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::AddOrUpdate {
+            category: default_asset_category("atom lsds"),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "name".to_string(),
+            invalid_value: "atom lsds".to_string(),
+            predicate: "1 to 64 alphanumeric, '_' or '-' characters".to_string(),
+        }),
+    );
+
+    let mut category = default_asset_category("atom_lsds");
+    category.denoms.push("uatom".to_string());
+    let res = mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::AddOrUpdate {
+            category,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "denoms".to_string(),
+            invalid_value: "uatom".to_string(),
+            predicate: "unique".to_string(),
+        }),
+    );
+
+    let mut category = default_asset_category("atom_lsds");
+    category.liquidation_threshold = Decimal::from_str("0.8").unwrap();
+    let res = mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::AddOrUpdate {
+            category,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "liquidation_threshold".to_string(),
+            invalid_value: "0.8".to_string(),
+            predicate: "> 0.85 (max LTV)".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn hls_correlations_must_reference_existing_category() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    let mut params = default_asset_params("uatom");
+    params.credit_manager.hls = Some(hls_with_category("atom_lsds"));
+
+    let res = mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params: params.clone(),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "correlations".to_string(),
+            invalid_value: "atom_lsds".to_string(),
+            predicate: "existing asset category".to_string(),
+        }),
+    );
+
+    mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::AddOrUpdate {
+            category: default_asset_category("atom_lsds"),
+        },
+    )
+    .unwrap();
+
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn removing_asset_category() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::AddOrUpdate {
+            category: default_asset_category("atom_lsds"),
+        },
+    )
+    .unwrap();
+
+    let mut params = default_asset_params("uatom");
+    params.credit_manager.hls = Some(hls_with_category("atom_lsds"));
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    )
+    .unwrap();
+
+    // can't remove a category still in use
+    let res = mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::Remove {
+            name: "atom_lsds".to_string(),
+        },
+    );
+    assert_err(
+        res,
+        AssetCategoryInUse {
+            name: "atom_lsds".to_string(),
+            referenced_by: "uatom".to_string(),
+        },
+    );
+
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params: default_asset_params("uatom"),
+        },
+    )
+    .unwrap();
+
+    // vaults referencing the category block the removal too
+    let mut config = default_vault_config("vault_addr_123");
+    config.hls = Some(hls_with_category("atom_lsds"));
+    mock.update_vault_config(
+        &owner,
+        VaultConfigUpdate::AddOrUpdate {
+            config,
+        },
+    )
+    .unwrap();
+
+    let res = mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::Remove {
+            name: "atom_lsds".to_string(),
+        },
+    );
+    assert_err(
+        res,
+        AssetCategoryInUse {
+            name: "atom_lsds".to_string(),
+            referenced_by: "vault_addr_123".to_string(),
+        },
+    );
+
+    mock.update_vault_config(
+        &owner,
+        VaultConfigUpdate::AddOrUpdate {
+            config: default_vault_config("vault_addr_123"),
+        },
+    )
+    .unwrap();
+
+    mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::Remove {
+            name: "atom_lsds".to_string(),
+        },
+    )
+    .unwrap();
+
+    let page = mock.query_asset_categories_page(None, None);
+    assert!(page.data.is_empty());
+    assert_eq!(page.total_count, 0);
+
+    // removing a category that doesn't exist fails
+    let res = mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::Remove {
+            name: "atom_lsds".to_string(),
+        },
+    );
+    assert_err(
+        res,
+        Std(NotFound {
+            kind: "mars_params::types::category::AssetCategory".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn pagination_query() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+    let names = ["a", "b", "c", "d", "e"].map(|n| n.to_string());
+
+    for name in names.iter() {
+        mock.update_asset_category(
+            &owner,
+            AssetCategoryUpdate::AddOrUpdate {
+                category: default_asset_category(name),
+            },
+        )
+        .unwrap();
+    }
+
+    let categories_a = mock.query_all_asset_categories(None, Some(2));
+    let categories_b =
+        mock.query_all_asset_categories(categories_a.last().map(|c| c.name.clone()), None);
+
+    let combined = categories_a
+        .iter()
+        .cloned()
+        .chain(categories_b.iter().cloned())
+        .map(|c| c.name)
+        .collect::<Vec<_>>();
+
+    assert_eq!(5, combined.len());
+    assert_contents_equal(&names, &combined);
+
+    // updating an existing category doesn't change the count
+    mock.update_asset_category(
+        &owner,
+        AssetCategoryUpdate::AddOrUpdate {
+            category: default_asset_category("a"),
+        },
+    )
+    .unwrap();
+    assert_eq!(mock.query_asset_categories_page(None, None).total_count, 5);
+}
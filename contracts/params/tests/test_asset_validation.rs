@@ -1,10 +1,13 @@
 use std::str::FromStr;
 
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Decimal, Uint128};
 use mars_params::{
     error::ContractError::Validation,
     msg::AssetParamsUpdate,
-    types::hls::{HlsAssetType, HlsParamsUnchecked},
+    types::{
+        hls::{HlsAssetType, HlsParamsUnchecked},
+        price_source::PriceSource,
+    },
 };
 use mars_utils::error::ValidationError::{InvalidDenom, InvalidParam};
 
@@ -176,12 +179,64 @@ fn hls_liq_threshold_gt_hls_max_ltv() {
     );
 }
 
+#[test]
+fn hls_max_ltv_gte_max_ltv() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.credit_manager.hls = Some(HlsParamsUnchecked {
+        max_loan_to_value: Decimal::from_str("0.59").unwrap(),
+        liquidation_threshold: Decimal::from_str("0.8").unwrap(),
+        correlations: vec![],
+    });
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "hls_max_loan_to_value".to_string(),
+            invalid_value: "0.59".to_string(),
+            predicate: ">= 0.6 (max LTV)".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn hls_liq_threshold_gte_liq_threshold() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.credit_manager.hls = Some(HlsParamsUnchecked {
+        max_loan_to_value: Decimal::from_str("0.6").unwrap(),
+        liquidation_threshold: Decimal::from_str("0.69").unwrap(),
+        correlations: vec![],
+    });
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "hls_liquidation_threshold".to_string(),
+            invalid_value: "0.69".to_string(),
+            predicate: ">= 0.7 (liquidation threshold)".to_string(),
+        }),
+    );
+}
+
 #[test]
 fn correlations_must_be_valid_denoms() {
     let mut mock = MockEnv::new().build().unwrap();
     let mut params = default_asset_params("denom_xyz");
     params.credit_manager.hls = Some(HlsParamsUnchecked {
-        max_loan_to_value: Decimal::from_str("0.5").unwrap(),
+        max_loan_to_value: Decimal::from_str("0.6").unwrap(),
         liquidation_threshold: Decimal::from_str("0.7").unwrap(),
         correlations: vec![HlsAssetType::Coin {
             denom: "AA".to_string(),
@@ -366,3 +421,405 @@ fn liquidation_bonus_param_max_lb_gt_min_lb() {
         }),
     );
 }
+
+#[test]
+fn liq_threshold_with_max_lb_less_than_one() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.max_loan_to_value = Decimal::from_str("0.9").unwrap();
+    params.liquidation_threshold = Decimal::from_str("0.95").unwrap();
+    params.liquidation_bonus.max_lb = Decimal::from_str("0.3").unwrap();
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "liquidation_threshold".to_string(),
+            invalid_value: "0.95".to_string(),
+            predicate: "< 1 / (1 + 0.3 (max LB))".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn liq_threshold_with_max_lb_equal_to_one() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.max_loan_to_value = Decimal::from_str("0.75").unwrap();
+    params.liquidation_threshold = Decimal::from_str("0.8").unwrap();
+    params.liquidation_bonus.max_lb = Decimal::from_str("0.25").unwrap();
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "liquidation_threshold".to_string(),
+            invalid_value: "0.8".to_string(),
+            predicate: "< 1 / (1 + 0.25 (max LB))".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn hls_liq_threshold_with_max_lb_less_than_one() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.credit_manager.hls = Some(HlsParamsUnchecked {
+        max_loan_to_value: Decimal::from_str("0.9").unwrap(),
+        liquidation_threshold: Decimal::from_str("0.93").unwrap(),
+        correlations: vec![],
+    });
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "hls_liquidation_threshold".to_string(),
+            invalid_value: "0.93".to_string(),
+            predicate: "< 1 / (1 + 0.08 (max LB))".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn fixed_price_source_greater_than_zero() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.price_source = Some(PriceSource::Fixed {
+        price: Decimal::zero(),
+    });
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "price".to_string(),
+            invalid_value: "0".to_string(),
+            predicate: "> 0".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn twap_price_source_window_size_within_range() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+
+    params.price_source = Some(PriceSource::ArithmeticTwap {
+        pool_id: 1,
+        window_size: 0,
+    });
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params: params.clone(),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "window_size".to_string(),
+            invalid_value: "0".to_string(),
+            predicate: "(0, 172800]".to_string(),
+        }),
+    );
+
+    params.price_source = Some(PriceSource::GeometricTwap {
+        pool_id: 1,
+        window_size: 172801,
+    });
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "window_size".to_string(),
+            invalid_value: "172801".to_string(),
+            predicate: "(0, 172800]".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn staked_derivative_price_source_transitive_denom_differs() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.price_source = Some(PriceSource::StakedDerivative {
+        transitive_denom: "denom_xyz".to_string(),
+        max_staleness: 3600,
+    });
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "transitive_denom".to_string(),
+            invalid_value: "denom_xyz".to_string(),
+            predicate: "!= denom_xyz (asset denom)".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn optimal_utilization_rate_within_range() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+
+    params.red_bank.interest_rate_model.as_mut().unwrap().optimal_utilization_rate =
+        Decimal::zero();
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params: params.clone(),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "optimal_utilization_rate".to_string(),
+            invalid_value: "0".to_string(),
+            predicate: "> 0".to_string(),
+        }),
+    );
+
+    params.red_bank.interest_rate_model.as_mut().unwrap().optimal_utilization_rate =
+        Decimal::from_str("1.01").unwrap();
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "optimal_utilization_rate".to_string(),
+            invalid_value: "1.01".to_string(),
+            predicate: "<= 1".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn interest_rate_slopes_monotonic() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.red_bank.interest_rate_model.as_mut().unwrap().slope_1 = Decimal::percent(30);
+    params.red_bank.interest_rate_model.as_mut().unwrap().slope_2 = Decimal::percent(20);
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "slope_2".to_string(),
+            invalid_value: "0.2".to_string(),
+            predicate: ">= 0.3 (slope_1)".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn reserve_factor_less_than_one() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.red_bank.reserve_factor = Decimal::one();
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "reserve_factor".to_string(),
+            invalid_value: "1".to_string(),
+            predicate: "< 1".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn borrow_cap_less_than_or_equal_to_deposit_cap() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.red_bank.deposit_cap = Uint128::new(1_000);
+    params.red_bank.borrow_cap = Uint128::new(1_001);
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "borrow_cap".to_string(),
+            invalid_value: "1001".to_string(),
+            predicate: "<= 1000 (deposit cap)".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn max_concentration_within_range() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+
+    params.credit_manager.max_concentration = Some(Decimal::zero());
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params: params.clone(),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "max_concentration".to_string(),
+            invalid_value: "0".to_string(),
+            predicate: "(0, 1]".to_string(),
+        }),
+    );
+
+    params.credit_manager.max_concentration = Some(Decimal::from_str("1.01").unwrap());
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "max_concentration".to_string(),
+            invalid_value: "1.01".to_string(),
+            predicate: "(0, 1]".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn close_factor_within_range() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+
+    params.close_factor = Some(Decimal::zero());
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params: params.clone(),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "close_factor".to_string(),
+            invalid_value: "0".to_string(),
+            predicate: "(0, 1]".to_string(),
+        }),
+    );
+
+    params.close_factor = Some(Decimal::from_str("1.01").unwrap());
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "close_factor".to_string(),
+            invalid_value: "1.01".to_string(),
+            predicate: "(0, 1]".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn interest_rate_model_is_optional() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.red_bank.interest_rate_model = None;
+
+    mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    )
+    .unwrap();
+
+    let params = mock.query_asset_params("denom_xyz");
+    assert_eq!(params.red_bank.interest_rate_model, None);
+}
+
+#[test]
+fn whitelisted_exposure_cap_greater_than_zero() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.credit_manager.whitelisted = true;
+    params.credit_manager.exposure_cap = Uint128::zero();
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params: params.clone(),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "exposure_cap".to_string(),
+            invalid_value: "0".to_string(),
+            predicate: "> 0 (whitelisted asset)".to_string(),
+        }),
+    );
+
+    // a zero cap is valid for assets that can't be held anyway
+    params.credit_manager.whitelisted = false;
+    mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    )
+    .unwrap();
+}
@@ -0,0 +1,60 @@
+use cosmwasm_std::{testing::MockApi, Decimal, Uint128};
+use mars_params::{error::ContractError, types::asset::AssetParams};
+
+use crate::helpers::default_asset_params;
+
+pub mod helpers;
+
+fn asset_params(exposure_cap: u128, max_concentration: Option<Decimal>) -> AssetParams {
+    let mut params = default_asset_params("atom");
+    params.credit_manager.exposure_cap = Uint128::new(exposure_cap);
+    params.credit_manager.max_concentration = max_concentration;
+    params.check(&MockApi::default()).unwrap()
+}
+
+#[test]
+fn within_limits() {
+    let params = asset_params(1_000, Some(Decimal::percent(50)));
+    params.assert_cm_limits(Uint128::new(1_000), Uint128::new(50), Uint128::new(100)).unwrap();
+}
+
+#[test]
+fn exposure_cap_exceeded() {
+    let params = asset_params(1_000, None);
+    let err = params
+        .assert_cm_limits(Uint128::new(1_001), Uint128::new(50), Uint128::new(100))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ExposureCapExceeded {
+            denom: "atom".to_string(),
+            cap: Uint128::new(1_000),
+            amount: Uint128::new(1_001),
+        }
+    );
+}
+
+#[test]
+fn concentration_limit_exceeded() {
+    let params = asset_params(1_000, Some(Decimal::percent(50)));
+    let err = params
+        .assert_cm_limits(Uint128::new(100), Uint128::new(51), Uint128::new(100))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ConcentrationLimitExceeded {
+            denom: "atom".to_string(),
+            max_concentration: Decimal::percent(50),
+            concentration: Decimal::percent(51),
+        }
+    );
+}
+
+#[test]
+fn concentration_not_checked_without_limit_or_account_value() {
+    let params = asset_params(1_000, None);
+    params.assert_cm_limits(Uint128::new(100), Uint128::new(100), Uint128::new(100)).unwrap();
+
+    let params = asset_params(1_000, Some(Decimal::percent(50)));
+    params.assert_cm_limits(Uint128::new(100), Uint128::zero(), Uint128::zero()).unwrap();
+}
@@ -3,8 +3,8 @@ use mars_owner::OwnerError;
 use mars_params::{
     error::ContractError::Owner,
     msg::{
-        AssetParamsUpdate, CmEmergencyUpdate, EmergencyUpdate, RedBankEmergencyUpdate,
-        VaultConfigUpdate,
+        AssetParamsFilter, AssetParamsUpdate, CmEmergencyUpdate, EmergencyUpdate,
+        RedBankEmergencyUpdate, VaultConfigUpdate, VaultConfigsFilter,
     },
 };
 
@@ -22,6 +22,12 @@ fn only_owner_can_invoke_emergency_powers() {
     );
     assert_err(res, Owner(OwnerError::NotEmergencyOwner {}));
 
+    let res = mock.emergency_update(
+        &bad_guy,
+        EmergencyUpdate::RedBank(RedBankEmergencyUpdate::SetZeroBorrowCap("xyz".to_string())),
+    );
+    assert_err(res, Owner(OwnerError::NotEmergencyOwner {}));
+
     let res = mock.emergency_update(
         &bad_guy,
         EmergencyUpdate::CreditManager(CmEmergencyUpdate::DisallowCoin("xyz".to_string())),
@@ -62,6 +68,8 @@ fn disabling_borrowing() {
 
     let params = mock.query_asset_params(&denom);
     assert!(params.red_bank.borrow_enabled);
+    let page = mock.query_asset_params_filtered_page(None, None, AssetParamsFilter::BorrowEnabled);
+    assert_eq!(page.total_count, 1);
 
     mock.emergency_update(
         &emergency_owner,
@@ -71,6 +79,36 @@ fn disabling_borrowing() {
 
     let params = mock.query_asset_params(&denom);
     assert!(!params.red_bank.borrow_enabled);
+    let page = mock.query_asset_params_filtered_page(None, None, AssetParamsFilter::BorrowEnabled);
+    assert_eq!(page.total_count, 0);
+    assert_eq!(mock.query_asset_params_page(None, None).total_count, 1);
+}
+
+#[test]
+fn set_zero_borrow_cap() {
+    let emergency_owner = Addr::unchecked("miles_morales");
+    let mut mock = MockEnv::new().emergency_owner(emergency_owner.as_str()).build().unwrap();
+    let denom = "atom".to_string();
+
+    mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params: default_asset_params(&denom),
+        },
+    )
+    .unwrap();
+
+    let params = mock.query_asset_params(&denom);
+    assert!(!params.red_bank.borrow_cap.is_zero());
+
+    mock.emergency_update(
+        &emergency_owner,
+        EmergencyUpdate::RedBank(RedBankEmergencyUpdate::SetZeroBorrowCap(denom.clone())),
+    )
+    .unwrap();
+
+    let params = mock.query_asset_params(&denom);
+    assert!(params.red_bank.borrow_cap.is_zero());
 }
 
 #[test]
@@ -146,6 +184,9 @@ fn set_zero_deposit_cap() {
 
     let params = mock.query_vault_config(&vault);
     assert!(!params.deposit_cap.amount.is_zero());
+    let page =
+        mock.query_vault_configs_filtered_page(None, None, VaultConfigsFilter::NonZeroDepositCap);
+    assert_eq!(page.total_count, 1);
 
     mock.emergency_update(
         &emergency_owner,
@@ -155,4 +196,8 @@ fn set_zero_deposit_cap() {
 
     let params = mock.query_vault_config(&vault);
     assert!(params.deposit_cap.amount.is_zero());
+    let page =
+        mock.query_vault_configs_filtered_page(None, None, VaultConfigsFilter::NonZeroDepositCap);
+    assert_eq!(page.total_count, 0);
+    assert_eq!(mock.query_vault_configs_page(None, None).total_count, 1);
 }
@@ -0,0 +1,58 @@
+use std::str::FromStr;
+
+use cosmwasm_std::Decimal;
+use mars_params::types::interest_rate_model::InterestRateModel;
+
+fn default_model() -> InterestRateModel {
+    InterestRateModel {
+        optimal_utilization_rate: Decimal::percent(80),
+        base: Decimal::percent(1),
+        slope_1: Decimal::percent(20),
+        slope_2: Decimal::percent(200),
+    }
+}
+
+#[test]
+fn borrow_rate_at_zero_utilization_is_base() {
+    let model = default_model();
+    let rate = model.get_borrow_rate(Decimal::zero()).unwrap();
+    assert_eq!(rate, model.base);
+}
+
+#[test]
+fn borrow_rate_below_optimal_utilization() {
+    let model = default_model();
+    // 0.01 + 0.2 * 0.4 / 0.8
+    let rate = model.get_borrow_rate(Decimal::percent(40)).unwrap();
+    assert_eq!(rate, Decimal::from_str("0.11").unwrap());
+
+    // 0.01 + 0.2
+    let rate = model.get_borrow_rate(Decimal::percent(80)).unwrap();
+    assert_eq!(rate, Decimal::from_str("0.21").unwrap());
+}
+
+#[test]
+fn borrow_rate_above_optimal_utilization() {
+    let model = default_model();
+    // 0.01 + 0.2 + 2 * (0.9 - 0.8) / (1 - 0.8)
+    let rate = model.get_borrow_rate(Decimal::percent(90)).unwrap();
+    assert_eq!(rate, Decimal::from_str("1.21").unwrap());
+
+    // 0.01 + 0.2 + 2
+    let rate = model.get_borrow_rate(Decimal::one()).unwrap();
+    assert_eq!(rate, Decimal::from_str("2.21").unwrap());
+}
+
+#[test]
+fn supply_rate_takes_out_reserve_factor() {
+    let model = default_model();
+    let utilization = Decimal::percent(40);
+    let borrow_rate = model.get_borrow_rate(utilization).unwrap();
+
+    // 0.11 * 0.4 * (1 - 0.1)
+    let rate = model.get_supply_rate(borrow_rate, utilization, Decimal::percent(10)).unwrap();
+    assert_eq!(rate, Decimal::from_str("0.0396").unwrap());
+
+    let rate = model.get_supply_rate(borrow_rate, Decimal::zero(), Decimal::percent(10)).unwrap();
+    assert_eq!(rate, Decimal::zero());
+}
@@ -0,0 +1,122 @@
+use cosmwasm_std::Uint128;
+use mars_params::{
+    error::ContractError::Validation, msg::AssetParamsUpdate, types::asset::IsolationSettings,
+};
+use mars_utils::error::ValidationError::{InvalidDenom, InvalidParam};
+
+use crate::helpers::{assert_err, default_asset_params, MockEnv};
+
+pub mod helpers;
+
+#[test]
+fn allowed_debt_denoms_must_be_valid_denoms() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.isolation = Some(IsolationSettings {
+        debt_ceiling: Uint128::new(1_000),
+        allowed_debt_denoms: vec!["AA".to_string()],
+    });
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidDenom {
+            reason: "Invalid denom length".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn allowed_debt_denoms_exclude_asset_itself() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.isolation = Some(IsolationSettings {
+        debt_ceiling: Uint128::new(1_000),
+        allowed_debt_denoms: vec!["uusdc".to_string(), "denom_xyz".to_string()],
+    });
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "allowed_debt_denoms".to_string(),
+            invalid_value: "denom_xyz".to_string(),
+            predicate: "!= denom_xyz (asset denom)".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn allowed_debt_denoms_unique() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut params = default_asset_params("denom_xyz");
+    params.isolation = Some(IsolationSettings {
+        debt_ceiling: Uint128::new(1_000),
+        allowed_debt_denoms: vec!["uusdc".to_string(), "uusdc".to_string()],
+    });
+
+    let res = mock.update_asset_params(
+        &mock.query_owner(),
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "allowed_debt_denoms".to_string(),
+            invalid_value: "uusdc".to_string(),
+            predicate: "unique".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn isolation_check() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params: default_asset_params("uatom"),
+        },
+    )
+    .unwrap();
+
+    let mut params = default_asset_params("unewcoin");
+    params.isolation = Some(IsolationSettings {
+        debt_ceiling: Uint128::new(1_000),
+        allowed_debt_denoms: vec!["uusdc".to_string()],
+    });
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    )
+    .unwrap();
+
+    // collateral not in isolation mode can back any debt
+    let res = mock.query_isolation_check("uatom", "uosmo");
+    assert!(res.allowed);
+    assert_eq!(res.debt_ceiling, None);
+
+    let res = mock.query_isolation_check("unewcoin", "uusdc");
+    assert!(res.allowed);
+    assert_eq!(res.debt_ceiling, Some(Uint128::new(1_000)));
+
+    let res = mock.query_isolation_check("unewcoin", "uosmo");
+    assert!(!res.allowed);
+    assert_eq!(res.debt_ceiling, Some(Uint128::new(1_000)));
+}
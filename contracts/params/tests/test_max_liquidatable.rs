@@ -0,0 +1,44 @@
+use cosmwasm_std::{testing::MockApi, Decimal, Uint128};
+use mars_params::types::asset::AssetParams;
+
+use crate::helpers::default_asset_params;
+
+pub mod helpers;
+
+fn asset_params(close_factor: Option<Decimal>, min_liquidation_value: Option<u128>) -> AssetParams {
+    let mut params = default_asset_params("atom");
+    params.close_factor = close_factor;
+    params.min_liquidation_value = min_liquidation_value.map(Uint128::new);
+    params.check(&MockApi::default()).unwrap()
+}
+
+#[test]
+fn whole_debt_without_close_factor() {
+    let params = asset_params(None, Some(100));
+    assert_eq!(params.max_liquidatable_value(Uint128::new(10_000)), Uint128::new(10_000));
+}
+
+#[test]
+fn close_factor_caps_liquidation() {
+    let params = asset_params(Some(Decimal::percent(50)), None);
+    assert_eq!(params.max_liquidatable_value(Uint128::new(10_000)), Uint128::new(5_000));
+
+    let params = asset_params(Some(Decimal::percent(50)), Some(1_000));
+    assert_eq!(params.max_liquidatable_value(Uint128::new(10_000)), Uint128::new(5_000));
+}
+
+#[test]
+fn whole_debt_below_min_liquidation_value() {
+    let params = asset_params(Some(Decimal::percent(50)), Some(1_000));
+    assert_eq!(params.max_liquidatable_value(Uint128::new(1_000)), Uint128::new(1_000));
+    assert_eq!(params.max_liquidatable_value(Uint128::new(800)), Uint128::new(800));
+}
+
+#[test]
+fn whole_debt_if_remainder_below_min_liquidation_value() {
+    let params = asset_params(Some(Decimal::percent(50)), Some(1_000));
+    // 50% of 1_900 leaves 950 of dust behind
+    assert_eq!(params.max_liquidatable_value(Uint128::new(1_900)), Uint128::new(1_900));
+    // 50% of 2_000 leaves exactly the min liquidation value
+    assert_eq!(params.max_liquidatable_value(Uint128::new(2_000)), Uint128::new(1_000));
+}
@@ -0,0 +1,113 @@
+use cosmwasm_std::{Decimal, Uint128};
+use mars_params::{
+    error::ContractError::Validation,
+    msg::{AssetParamsUpdate, MigrateMsg},
+};
+use mars_utils::error::ValidationError::InvalidParam;
+
+use crate::helpers::{assert_err, default_asset_params, MockEnv};
+
+pub mod helpers;
+
+/// Asset params in the format stored before the exposure cap, borrow cap and reserve factor were
+/// introduced
+const LEGACY_ASSET_PARAMS: &str = r#"{
+    "denom": "atom",
+    "credit_manager": { "whitelisted": false, "hls": null },
+    "red_bank": {
+        "deposit_enabled": true,
+        "borrow_enabled": false,
+        "deposit_cap": "1000000000"
+    },
+    "max_loan_to_value": "0.6",
+    "liquidation_threshold": "0.7",
+    "liquidation_bonus": {
+        "starting_lb": "0.04",
+        "slope": "2",
+        "min_lb": "0.01",
+        "max_lb": "0.08"
+    },
+    "protocol_liquidation_fee": "0.02"
+}"#;
+
+#[test]
+fn migration_backfills_legacy_asset_params() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    mock.save_raw_asset_params("atom", LEGACY_ASSET_PARAMS);
+
+    let mut params = default_asset_params("osmo");
+    params.red_bank.reserve_factor = Decimal::percent(20);
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    )
+    .unwrap();
+
+    let mut params = default_asset_params("usdc");
+    params.red_bank.reserve_factor = Decimal::zero();
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params,
+        },
+    )
+    .unwrap();
+
+    let res = mock
+        .migrate(
+            &owner,
+            MigrateMsg {
+                default_reserve_factor: Decimal::percent(5),
+            },
+        )
+        .unwrap();
+    let updated_assets = res
+        .events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == "updated_assets")
+        .map(|a| a.value.clone());
+    assert_eq!(updated_assets, Some("1".to_string()));
+
+    let params = mock.query_asset_params("atom");
+    assert_eq!(params.red_bank.reserve_factor, Decimal::percent(5));
+    assert_eq!(params.red_bank.interest_rate_model, None);
+    assert_eq!(params.red_bank.borrow_cap, Uint128::new(1_000_000_000));
+    assert_eq!(params.credit_manager.exposure_cap, Uint128::MAX);
+
+    // explicitly configured reserve factors, including zero, are left untouched
+    let params = mock.query_asset_params("osmo");
+    assert_eq!(params.red_bank.reserve_factor, Decimal::percent(20));
+    assert_eq!(params.red_bank.borrow_cap, Uint128::new(800_000_000));
+    let params = mock.query_asset_params("usdc");
+    assert_eq!(params.red_bank.reserve_factor, Decimal::zero());
+
+    // entries stored without going through the contract are counted on migration
+    let page = mock.query_asset_params_page(None, None);
+    assert_eq!(page.total_count, 3);
+}
+
+#[test]
+fn migration_validates_default_reserve_factor() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    let res = mock.migrate(
+        &owner,
+        MigrateMsg {
+            default_reserve_factor: Decimal::one(),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "reserve_factor".to_string(),
+            invalid_value: "1".to_string(),
+            predicate: "< 1".to_string(),
+        }),
+    );
+}
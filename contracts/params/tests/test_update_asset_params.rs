@@ -1,6 +1,13 @@
-use cosmwasm_std::Addr;
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Decimal};
 use mars_owner::OwnerError;
-use mars_params::{error::ContractError::Owner, msg::AssetParamsUpdate};
+use mars_params::{
+    error::ContractError::Owner,
+    msg::{AssetParamsFilter, AssetParamsUpdate},
+    query::MAX_LIMIT,
+    types::{hls::HlsParamsUnchecked, price_source::PriceSource},
+};
 
 use crate::helpers::{assert_contents_equal, assert_err, default_asset_params, MockEnv};
 
@@ -143,6 +150,30 @@ fn update_existing_asset_params() {
     assert!(!asset_params.red_bank.deposit_enabled);
 }
 
+#[test]
+fn storing_price_source() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+    let denom0 = "atom".to_string();
+
+    let mut params = default_asset_params(&denom0);
+    params.price_source = Some(PriceSource::GeometricTwap {
+        pool_id: 1,
+        window_size: 1800,
+    });
+
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params: params.clone(),
+        },
+    )
+    .unwrap();
+
+    let asset_params = mock.query_asset_params(&denom0);
+    assert_eq!(params.price_source, asset_params.price_source);
+}
+
 #[test]
 fn removing_from_asset_params() {
     let mut mock = MockEnv::new().build().unwrap();
@@ -249,3 +280,135 @@ fn pagination_query() {
 
     assert_contents_equal(&[denom0, denom1, denom2, denom3, denom4, denom5], &combined)
 }
+
+#[test]
+fn batch_query() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    for denom in ["atom", "osmo"] {
+        mock.update_asset_params(
+            &owner,
+            AssetParamsUpdate::AddOrUpdate {
+                params: default_asset_params(denom),
+            },
+        )
+        .unwrap();
+    }
+
+    let res = mock.query_asset_params_batch(&["atom", "juno", "osmo"]);
+    assert_eq!(3, res.len());
+    assert_eq!(res["atom"], Some(mock.query_asset_params("atom")));
+    assert_eq!(res["osmo"], Some(mock.query_asset_params("osmo")));
+    assert_eq!(res["juno"], None);
+}
+
+#[test]
+fn filtered_query() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    let mut atom = default_asset_params("atom");
+    atom.credit_manager.whitelisted = true;
+    atom.red_bank.borrow_enabled = true;
+    let mut osmo = default_asset_params("osmo");
+    osmo.credit_manager.whitelisted = true;
+    osmo.credit_manager.hls = Some(HlsParamsUnchecked {
+        max_loan_to_value: Decimal::from_str("0.8").unwrap(),
+        liquidation_threshold: Decimal::from_str("0.85").unwrap(),
+        correlations: vec![],
+    });
+    let juno = default_asset_params("juno");
+
+    for params in [atom, osmo, juno] {
+        mock.update_asset_params(
+            &owner,
+            AssetParamsUpdate::AddOrUpdate {
+                params,
+            },
+        )
+        .unwrap();
+    }
+
+    let denoms = |filter: AssetParamsFilter| {
+        mock.query_all_asset_params_filtered(None, None, filter)
+            .into_iter()
+            .map(|p| p.denom)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(denoms(AssetParamsFilter::CmWhitelisted), vec!["atom", "osmo"]);
+    assert_eq!(denoms(AssetParamsFilter::BorrowEnabled), vec!["atom"]);
+    assert_eq!(denoms(AssetParamsFilter::HlsEnabled), vec!["osmo"]);
+
+    // pagination within the filtered results
+    let params =
+        mock.query_all_asset_params_filtered(None, Some(1), AssetParamsFilter::CmWhitelisted);
+    assert_eq!(params.len(), 1);
+    assert_eq!(params[0].denom, "atom");
+    let params = mock.query_all_asset_params_filtered(
+        Some("atom".to_string()),
+        None,
+        AssetParamsFilter::CmWhitelisted,
+    );
+    assert_eq!(params.len(), 1);
+    assert_eq!(params[0].denom, "osmo");
+
+    // index is kept up to date on updates
+    mock.update_asset_params(
+        &owner,
+        AssetParamsUpdate::AddOrUpdate {
+            params: default_asset_params("atom"),
+        },
+    )
+    .unwrap();
+    let params = mock.query_all_asset_params_filtered(None, None, AssetParamsFilter::BorrowEnabled);
+    assert!(params.is_empty());
+}
+
+#[test]
+fn pagination_cursor_and_total_count() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    for denom in ["atom", "juno", "osmo"] {
+        mock.update_asset_params(
+            &owner,
+            AssetParamsUpdate::AddOrUpdate {
+                params: default_asset_params(denom),
+            },
+        )
+        .unwrap();
+    }
+
+    let page = mock.query_asset_params_page(None, Some(2));
+    assert_eq!(page.data.len(), 2);
+    assert_eq!(page.next_start_after, Some("juno".to_string()));
+    assert_eq!(page.total_count, 3);
+
+    let page = mock.query_asset_params_page(page.next_start_after, Some(2));
+    assert_eq!(page.data.len(), 1);
+    assert_eq!(page.data[0].denom, "osmo");
+    assert_eq!(page.next_start_after, None);
+    assert_eq!(page.total_count, 3);
+}
+
+#[test]
+fn pagination_limit_is_capped() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    for i in 0..(MAX_LIMIT + 5) {
+        mock.update_asset_params(
+            &owner,
+            AssetParamsUpdate::AddOrUpdate {
+                params: default_asset_params(&format!("denom{i:02}")),
+            },
+        )
+        .unwrap();
+    }
+
+    let page = mock.query_asset_params_page(None, Some(MAX_LIMIT + 5));
+    assert_eq!(page.data.len(), MAX_LIMIT as usize);
+    assert!(page.next_start_after.is_some());
+    assert_eq!(page.total_count, (MAX_LIMIT + 5) as u64);
+}
@@ -4,7 +4,7 @@ use cosmwasm_std::{Decimal, StdError::GenericErr};
 use mars_params::{
     error::ContractError::{Std, Validation},
     msg::VaultConfigUpdate,
-    types::hls::HlsParamsUnchecked,
+    types::{asset::LiquidationBonus, hls::HlsParamsUnchecked, vault::VaultLockup},
 };
 use mars_utils::error::ValidationError::InvalidParam;
 
@@ -172,3 +172,177 @@ fn vault_hls_liq_threshold_gt_max_ltv() {
         }),
     );
 }
+
+#[test]
+fn vault_hls_max_ltv_gte_max_ltv() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut config = default_vault_config("vault_xyz");
+    config.hls = Some(HlsParamsUnchecked {
+        max_loan_to_value: Decimal::from_str("0.46").unwrap(),
+        liquidation_threshold: Decimal::from_str("0.6").unwrap(),
+        correlations: vec![],
+    });
+
+    let res = mock.update_vault_config(
+        &mock.query_owner(),
+        VaultConfigUpdate::AddOrUpdate {
+            config,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "hls_max_loan_to_value".to_string(),
+            invalid_value: "0.46".to_string(),
+            predicate: ">= 0.47 (max LTV)".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn vault_hls_liq_threshold_gte_liq_threshold() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut config = default_vault_config("vault_xyz");
+    config.hls = Some(HlsParamsUnchecked {
+        max_loan_to_value: Decimal::from_str("0.47").unwrap(),
+        liquidation_threshold: Decimal::from_str("0.49").unwrap(),
+        correlations: vec![],
+    });
+
+    let res = mock.update_vault_config(
+        &mock.query_owner(),
+        VaultConfigUpdate::AddOrUpdate {
+            config,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "hls_liquidation_threshold".to_string(),
+            invalid_value: "0.49".to_string(),
+            predicate: ">= 0.5 (liquidation threshold)".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn vault_liquidation_bonus_validated() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut config = default_vault_config("vault_xyz");
+    config.liquidation_bonus = Some(LiquidationBonus {
+        starting_lb: Decimal::percent(4),
+        slope: Decimal::from_str("2.0").unwrap(),
+        min_lb: Decimal::percent(1),
+        max_lb: Decimal::from_str("0.31").unwrap(),
+    });
+
+    let res = mock.update_vault_config(
+        &mock.query_owner(),
+        VaultConfigUpdate::AddOrUpdate {
+            config,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "max_lb".to_string(),
+            invalid_value: "0.31".to_string(),
+            predicate: "[0.05, 0.3]".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn vault_liq_threshold_with_max_lb_less_than_one() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut config = default_vault_config("vault_xyz");
+    config.max_loan_to_value = Decimal::from_str("0.8").unwrap();
+    config.liquidation_threshold = Decimal::from_str("0.85").unwrap();
+    config.liquidation_bonus = Some(LiquidationBonus {
+        starting_lb: Decimal::percent(4),
+        slope: Decimal::from_str("2.0").unwrap(),
+        min_lb: Decimal::percent(1),
+        max_lb: Decimal::percent(20),
+    });
+
+    let res = mock.update_vault_config(
+        &mock.query_owner(),
+        VaultConfigUpdate::AddOrUpdate {
+            config,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "liquidation_threshold".to_string(),
+            invalid_value: "0.85".to_string(),
+            predicate: "< 1 / (1 + 0.2 (max LB))".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn vault_protocol_liquidation_fee_less_than_one() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut config = default_vault_config("vault_xyz");
+    config.protocol_liquidation_fee = Some(Decimal::one());
+
+    let res = mock.update_vault_config(
+        &mock.query_owner(),
+        VaultConfigUpdate::AddOrUpdate {
+            config,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "protocol_liquidation_fee".to_string(),
+            invalid_value: "1".to_string(),
+            predicate: "< 1".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn vault_lockup_duration_within_range() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let mut config = default_vault_config("vault_xyz");
+
+    config.lockup = Some(VaultLockup {
+        duration: 0,
+        force_unlock_supported: false,
+    });
+    let res = mock.update_vault_config(
+        &mock.query_owner(),
+        VaultConfigUpdate::AddOrUpdate {
+            config: config.clone(),
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "lockup_duration".to_string(),
+            invalid_value: "0".to_string(),
+            predicate: "(0, 31536000]".to_string(),
+        }),
+    );
+
+    config.lockup = Some(VaultLockup {
+        duration: 31_536_001,
+        force_unlock_supported: true,
+    });
+    let res = mock.update_vault_config(
+        &mock.query_owner(),
+        VaultConfigUpdate::AddOrUpdate {
+            config,
+        },
+    );
+    assert_err(
+        res,
+        Validation(InvalidParam {
+            param_name: "lockup_duration".to_string(),
+            invalid_value: "31536001".to_string(),
+            predicate: "(0, 31536000]".to_string(),
+        }),
+    );
+}
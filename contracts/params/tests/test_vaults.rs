@@ -3,7 +3,9 @@ use std::str::FromStr;
 use cosmwasm_std::{Addr, Decimal};
 use mars_owner::OwnerError;
 use mars_params::{
-    error::ContractError::Owner, msg::VaultConfigUpdate, types::vault::VaultConfigUnchecked,
+    error::ContractError::Owner,
+    msg::{VaultConfigUpdate, VaultConfigsFilter},
+    types::vault::{VaultConfigUnchecked, VaultLockup},
 };
 
 use crate::helpers::{assert_contents_equal, assert_err, default_vault_config, MockEnv};
@@ -31,6 +33,9 @@ fn only_owner_can_update_vault_configs() {
                 liquidation_threshold: Default::default(),
                 whitelisted: false,
                 hls: None,
+                liquidation_bonus: None,
+                protocol_liquidation_fee: None,
+                lockup: None,
             },
         },
     );
@@ -153,6 +158,34 @@ fn update_existing_vault_configs() {
     assert_eq!(vault_config.max_loan_to_value, new_max_ltv);
 }
 
+#[test]
+fn lockup_surfaced_in_queries() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+    let vault0 = "vault_addr_0".to_string();
+
+    let lockup = VaultLockup {
+        duration: 1_209_600,
+        force_unlock_supported: true,
+    };
+    let mut config = default_vault_config(&vault0);
+    config.lockup = Some(lockup.clone());
+
+    mock.update_vault_config(
+        &owner,
+        VaultConfigUpdate::AddOrUpdate {
+            config,
+        },
+    )
+    .unwrap();
+
+    let vault_config = mock.query_vault_config(&vault0);
+    assert_eq!(vault_config.lockup, Some(lockup.clone()));
+
+    let all_vault_configs = mock.query_all_vault_configs(None, None);
+    assert_eq!(all_vault_configs.first().unwrap().lockup, Some(lockup));
+}
+
 #[test]
 fn pagination_query() {
     let mut mock = MockEnv::new().build().unwrap();
@@ -225,3 +258,71 @@ fn pagination_query() {
 
     assert_contents_equal(&[vault0, vault1, vault2, vault3, vault4, vault5], &combined)
 }
+
+#[test]
+fn batch_query() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    for vault in ["vault_addr_0", "vault_addr_1"] {
+        mock.update_vault_config(
+            &owner,
+            VaultConfigUpdate::AddOrUpdate {
+                config: default_vault_config(vault),
+            },
+        )
+        .unwrap();
+    }
+
+    let res = mock.query_vault_configs_batch(&[
+        "vault_addr_0",
+        "vault_addr_1",
+        "vault_addr_2",
+        "Vault_Addr_3",
+    ]);
+    assert_eq!(4, res.len());
+    assert_eq!(res["vault_addr_0"], Some(mock.query_vault_config("vault_addr_0")));
+    assert_eq!(res["vault_addr_1"], Some(mock.query_vault_config("vault_addr_1")));
+    assert_eq!(res["vault_addr_2"], None);
+    // invalid addresses don't fail the whole batch
+    assert_eq!(res["Vault_Addr_3"], None);
+}
+
+#[test]
+fn filtered_query() {
+    let mut mock = MockEnv::new().build().unwrap();
+    let owner = mock.query_owner();
+
+    let vault0 = default_vault_config("vault_addr_0");
+    let mut vault1 = default_vault_config("vault_addr_1");
+    vault1.whitelisted = false;
+    let mut vault2 = default_vault_config("vault_addr_2");
+    vault2.deposit_cap.amount = Default::default();
+
+    for config in [vault0, vault1, vault2] {
+        mock.update_vault_config(
+            &owner,
+            VaultConfigUpdate::AddOrUpdate {
+                config,
+            },
+        )
+        .unwrap();
+    }
+
+    let addrs = |filter: VaultConfigsFilter| {
+        mock.query_all_vault_configs_filtered(None, None, filter)
+            .into_iter()
+            .map(|c| c.addr.to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(addrs(VaultConfigsFilter::Whitelisted), vec!["vault_addr_0", "vault_addr_2"]);
+    assert_eq!(addrs(VaultConfigsFilter::NonZeroDepositCap), vec!["vault_addr_0", "vault_addr_1"]);
+
+    let configs = mock.query_all_vault_configs_filtered(
+        Some("vault_addr_0".to_string()),
+        None,
+        VaultConfigsFilter::Whitelisted,
+    );
+    assert_eq!(configs.len(), 1);
+    assert_eq!(configs[0].addr, "vault_addr_2");
+}
@@ -1,19 +1,28 @@
-use std::marker::PhantomData;
+use std::{collections::VecDeque, marker::PhantomData};
 
 use cosmwasm_std::{
     to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, CustomMsg, CustomQuery, Decimal, Deps,
-    DepsMut, Env, MessageInfo, Response, WasmMsg,
+    DepsMut, Env, Event, MessageInfo, Order, Response, Uint128, WasmMsg,
 };
-use cw_paginate::paginate_map;
-use cw_storage_plus::{Bound, Map};
+use cw_storage_plus::{Bound, Item, Map};
 use mars_owner::{Owner, OwnerInit::SetInitialOwner, OwnerUpdate};
 use mars_swapper::msgs::{
-    EstimateExactInSwapResponse, ExecuteMsg, InstantiateMsg, QueryMsg, RouteResponse,
-    RoutesResponse,
+    EstimateExactInSwapResponse, EstimateExactOutSwapResponse, ExecuteMsg, FindRouteResponse,
+    InstantiateMsg, MigrateMsg, PairRoute, QueryMsg, RouteCandidate, RouteCandidatesResponse,
+    RouteResponse, RoutesResponse, SwapSplit,
 };
 
 use crate::{ContractError, ContractResult, Route};
 
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 30;
+
+/// Max number of stored routes joined together when composing a route for a pair without one
+pub const MAX_ROUTE_HOPS: usize = 3;
+
+/// A part of a swap: the route's index among the pair's candidates, the route and the amount in
+type SwapLeg<R> = (usize, R, Uint128);
+
 pub struct SwapBase<'a, Q, M, R>
 where
     Q: CustomQuery,
@@ -22,8 +31,14 @@ where
 {
     /// The contract's owner who has special rights to update contract
     pub owner: Owner<'a>,
+    /// The maximum slippage allowed for swaps
+    pub max_slippage: Item<'a, Decimal>,
     /// The trade route for each pair of input/output assets
     pub routes: Map<'a, (String, String), R>,
+    /// Number of entries in `routes`
+    pub route_count: Item<'a, u64>,
+    /// Additional routes considered for exact-in swaps of each pair
+    pub route_candidates: Map<'a, (String, String), Vec<R>>,
     /// Phantom data holds generics
     pub custom_query: PhantomData<Q>,
     pub custom_message: PhantomData<M>,
@@ -38,7 +53,10 @@ where
     fn default() -> Self {
         Self {
             owner: Owner::new("owner"),
+            max_slippage: Item::new("max_slippage"),
             routes: Map::new("routes"),
+            route_count: Item::new("route_count"),
+            route_candidates: Map::new("route_candidates"),
             custom_query: PhantomData,
             custom_message: PhantomData,
         }
@@ -63,9 +81,26 @@ where
                 owner: msg.owner,
             },
         )?;
+
+        assert_max_slippage(msg.max_slippage)?;
+        self.max_slippage.save(deps.storage, &msg.max_slippage)?;
+
         Ok(Response::default())
     }
 
+    pub fn migrate(&self, deps: DepsMut<Q>, msg: MigrateMsg) -> ContractResult<Response<M>> {
+        assert_max_slippage(msg.max_slippage)?;
+        self.max_slippage.save(deps.storage, &msg.max_slippage)?;
+
+        // Routes stored before the count was introduced
+        let route_count = self.routes.keys_raw(deps.storage, None, None, Order::Ascending).count();
+        self.route_count.save(deps.storage, &(route_count as u64))?;
+
+        Ok(Response::new()
+            .add_attribute("action", "migrate")
+            .add_attribute("max_slippage", msg.max_slippage.to_string()))
+    }
+
     pub fn execute(
         &self,
         deps: DepsMut<Q>,
@@ -75,35 +110,99 @@ where
     ) -> ContractResult<Response<M>> {
         match msg {
             ExecuteMsg::UpdateOwner(update) => self.update_owner(deps, info, update),
+            ExecuteMsg::UpdateMaxSlippage {
+                max_slippage,
+            } => self.update_max_slippage(deps, info, max_slippage),
             ExecuteMsg::SetRoute {
                 denom_in,
                 denom_out,
                 route,
-            } => self.set_route(deps, info.sender, denom_in, denom_out, route),
+                also_reverse,
+            } => self.set_route(deps, info.sender, denom_in, denom_out, route, also_reverse),
+            ExecuteMsg::SetRoutes {
+                routes,
+            } => self.set_routes(deps, info.sender, routes),
+            ExecuteMsg::RemoveRoute {
+                denom_in,
+                denom_out,
+            } => self.remove_route(deps, info.sender, denom_in, denom_out),
+            ExecuteMsg::SetRouteCandidates {
+                denom_in,
+                denom_out,
+                routes,
+            } => self.set_route_candidates(deps, info.sender, denom_in, denom_out, routes),
             ExecuteMsg::SwapExactIn {
                 coin_in,
                 denom_out,
                 slippage,
-            } => self.swap_exact_in(deps, env, info, coin_in, denom_out, slippage),
+                min_receive,
+                recipient,
+                callback,
+                split,
+            } => self.swap_exact_in(
+                deps,
+                env,
+                info,
+                coin_in,
+                denom_out,
+                slippage,
+                min_receive,
+                recipient,
+                callback,
+                split,
+            ),
+            ExecuteMsg::SwapExactOut {
+                denom_in,
+                coin_out,
+                max_slippage,
+            } => self.swap_exact_out(deps, env, info, denom_in, coin_out, max_slippage),
             ExecuteMsg::TransferResult {
                 recipient,
                 denom_in,
                 denom_out,
-            } => self.transfer_result(deps, env, info, recipient, denom_in, denom_out),
+                min_receive,
+                denom_in_balance_before,
+                denom_out_balance_before,
+                callback,
+            } => self.transfer_result(
+                deps,
+                env,
+                info,
+                recipient,
+                denom_in,
+                denom_out,
+                min_receive,
+                denom_in_balance_before,
+                denom_out_balance_before,
+                callback,
+            ),
         }
     }
 
     pub fn query(&self, deps: Deps<Q>, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
         let res = match msg {
             QueryMsg::Owner {} => to_binary(&self.owner.query(deps.storage)?),
+            QueryMsg::MaxSlippage {} => to_binary(&self.max_slippage.load(deps.storage)?),
             QueryMsg::EstimateExactInSwap {
                 coin_in,
                 denom_out,
             } => to_binary(&self.estimate_exact_in_swap(deps, env, coin_in, denom_out)?),
+            QueryMsg::EstimateExactOutSwap {
+                denom_in,
+                coin_out,
+            } => to_binary(&self.estimate_exact_out_swap(deps, env, denom_in, coin_out)?),
             QueryMsg::Route {
                 denom_in,
                 denom_out,
             } => to_binary(&self.query_route(deps, denom_in, denom_out)?),
+            QueryMsg::FindRoute {
+                denom_in,
+                denom_out,
+            } => to_binary(&self.find_route(deps, &denom_in, &denom_out)?),
+            QueryMsg::RouteCandidates {
+                coin_in,
+                denom_out,
+            } => to_binary(&self.query_route_candidates(deps, env, coin_in, denom_out)?),
             QueryMsg::Routes {
                 start_after,
                 limit,
@@ -131,13 +230,201 @@ where
         start_after: Option<(String, String)>,
         limit: Option<u32>,
     ) -> ContractResult<RoutesResponse<R>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let total_count = self.route_count.may_load(deps.storage)?.unwrap_or_default();
+
         let start = start_after.map(Bound::exclusive);
-        paginate_map(&self.routes, deps.storage, start, limit, |(denom_in, denom_out), route| {
-            Ok(RouteResponse {
-                denom_in,
-                denom_out,
+        let mut routes = self.routes.range(deps.storage, start, None, Order::Ascending);
+
+        let data = routes
+            .by_ref()
+            .take(limit)
+            .map(|item| {
+                let ((denom_in, denom_out), route) = item?;
+                Ok(RouteResponse {
+                    denom_in,
+                    denom_out,
+                    route,
+                })
+            })
+            .collect::<ContractResult<Vec<_>>>()?;
+
+        // Only point to a next page if there are routes left after this one
+        let next_start_after = match routes.next() {
+            Some(_) => data.last().map(|r| (r.denom_in.clone(), r.denom_out.clone())),
+            None => None,
+        };
+
+        Ok(RoutesResponse {
+            data,
+            next_start_after,
+            total_count,
+        })
+    }
+
+    /// Get the stored route for a pair or, if there is none, join stored routes through intermediate
+    /// denoms into one. Paths with fewer hops are preferred and no denom is visited twice.
+    fn find_route(
+        &self,
+        deps: Deps<Q>,
+        denom_in: &str,
+        denom_out: &str,
+    ) -> ContractResult<FindRouteResponse<R>> {
+        let direct =
+            self.routes.may_load(deps.storage, (denom_in.to_string(), denom_out.to_string()))?;
+        if let Some(route) = direct {
+            return Ok(FindRouteResponse {
+                denoms: vec![denom_in.to_string(), denom_out.to_string()],
                 route,
+            });
+        }
+
+        // Breadth-first search over the stored routes, so shorter paths are found first. Only the
+        // routes starting from the last denom of each path are loaded.
+        let mut paths = VecDeque::from([(vec![denom_in.to_string()], vec![])]);
+        while let Some((path, legs)) = paths.pop_front() {
+            let last = path[path.len() - 1].clone();
+            let next_routes =
+                self.routes.prefix(last).range(deps.storage, None, None, Order::Ascending);
+
+            for item in next_routes {
+                let (next, leg) = item?;
+
+                // the path must not contain a loop
+                if path.contains(&next) {
+                    continue;
+                }
+
+                let mut next_path = path.clone();
+                next_path.push(next.clone());
+                let mut next_legs = legs.clone();
+                next_legs.push(leg);
+
+                if next == denom_out {
+                    let route = R::concat(&next_legs)?;
+
+                    // the joined route must be valid as a whole, otherwise keep searching
+                    if route.validate(&deps.querier, denom_in, denom_out).is_ok() {
+                        return Ok(FindRouteResponse {
+                            denoms: next_path,
+                            route,
+                        });
+                    }
+                } else if next_legs.len() < MAX_ROUTE_HOPS {
+                    paths.push_back((next_path, next_legs));
+                }
+            }
+        }
+
+        Err(ContractError::NoRouteFound {
+            denom_in: denom_in.to_string(),
+            denom_out: denom_out.to_string(),
+        })
+    }
+
+    /// All routes for a pair: the stored (or composed) route first, followed by the candidates
+    fn load_route_candidates(
+        &self,
+        deps: Deps<Q>,
+        denom_in: &str,
+        denom_out: &str,
+    ) -> ContractResult<Vec<R>> {
+        let mut routes = self
+            .route_candidates
+            .may_load(deps.storage, (denom_in.to_string(), denom_out.to_string()))?
+            .unwrap_or_default();
+
+        match self.find_route(deps, denom_in, denom_out) {
+            Ok(found) => routes.insert(0, found.route),
+            Err(ContractError::NoRouteFound {
+                ..
+            }) if !routes.is_empty() => {}
+            Err(err) => return Err(err),
+        }
+
+        Ok(routes)
+    }
+
+    /// Pick the route with the best estimated output for an exact-in swap, along with its
+    /// position in the list of candidates. Routes that can't be estimated are skipped.
+    fn select_exact_in_route(
+        &self,
+        deps: Deps<Q>,
+        env: &Env,
+        coin_in: &Coin,
+        denom_out: &str,
+    ) -> ContractResult<(usize, R)> {
+        let mut routes = self.load_route_candidates(deps, &coin_in.denom, denom_out)?;
+
+        // Nothing to choose from, no need to estimate
+        if routes.len() == 1 {
+            return Ok((0, routes.remove(0)));
+        }
+
+        routes
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, route)| {
+                let estimate = route.estimate_exact_in_swap(&deps.querier, env, coin_in).ok()?;
+                Some((i, route, estimate.amount))
+            })
+            // on equal estimates, prefer the earlier route
+            .max_by(|(i_a, _, amount_a), (i_b, _, amount_b)| {
+                amount_a.cmp(amount_b).then(i_b.cmp(i_a))
             })
+            .map(|(i, route, _)| (i, route))
+            .ok_or_else(|| ContractError::NoRouteFound {
+                denom_in: coin_in.denom.clone(),
+                denom_out: denom_out.to_string(),
+            })
+    }
+
+    /// Split an exact-in swap across the pair's candidate routes. Legs without any input are left out.
+    fn split_exact_in(
+        &self,
+        deps: Deps<Q>,
+        coin_in: &Coin,
+        denom_out: &str,
+        split: SwapSplit,
+    ) -> ContractResult<Vec<SwapLeg<R>>> {
+        let routes = self.load_route_candidates(deps, &coin_in.denom, denom_out)?;
+
+        let SwapSplit::Weights(weights) = split;
+        let amounts = split_by_weights(coin_in.amount, &weights, routes.len())?;
+
+        Ok(routes
+            .into_iter()
+            .zip(amounts)
+            .enumerate()
+            .filter(|(_, (_, amount))| !amount.is_zero())
+            .map(|(index, (route, amount))| (index, route, amount))
+            .collect())
+    }
+
+    fn query_route_candidates(
+        &self,
+        deps: Deps<Q>,
+        env: Env,
+        coin_in: Coin,
+        denom_out: String,
+    ) -> ContractResult<RouteCandidatesResponse<R>> {
+        let candidates = self
+            .load_route_candidates(deps, &coin_in.denom, &denom_out)?
+            .into_iter()
+            .map(|route| {
+                let amount_out = route
+                    .estimate_exact_in_swap(&deps.querier, &env, &coin_in)
+                    .ok()
+                    .map(|estimate| estimate.amount);
+                RouteCandidate {
+                    route,
+                    amount_out,
+                }
+            })
+            .collect();
+
+        Ok(RouteCandidatesResponse {
+            candidates,
         })
     }
 
@@ -148,10 +435,22 @@ where
         coin_in: Coin,
         denom_out: String,
     ) -> ContractResult<EstimateExactInSwapResponse> {
-        let route = self.routes.load(deps.storage, (coin_in.denom.clone(), denom_out))?;
+        let (_, route) = self.select_exact_in_route(deps, &env, &coin_in, &denom_out)?;
         route.estimate_exact_in_swap(&deps.querier, &env, &coin_in)
     }
 
+    fn estimate_exact_out_swap(
+        &self,
+        deps: Deps<Q>,
+        env: Env,
+        denom_in: String,
+        coin_out: Coin,
+    ) -> ContractResult<EstimateExactOutSwapResponse> {
+        let route = self.find_route(deps, &denom_in, &coin_out.denom)?.route;
+        route.estimate_exact_out_swap(&deps.querier, &env, &denom_in, &coin_out)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn swap_exact_in(
         &self,
         deps: DepsMut<Q>,
@@ -160,33 +459,165 @@ where
         coin_in: Coin,
         denom_out: String,
         slippage: Decimal,
+        min_receive: Option<Uint128>,
+        recipient: Option<String>,
+        callback: Option<Binary>,
+        split: Option<SwapSplit>,
     ) -> ContractResult<Response<M>> {
-        let swap_msg = self
-            .routes
-            .load(deps.storage, (coin_in.denom.clone(), denom_out.clone()))?
-            .build_exact_in_swap_msg(&deps.querier, &env, &coin_in, slippage)?;
+        self.assert_slippage(deps.as_ref(), slippage)?;
+        assert_exact_funds(&info.funds, &coin_in)?;
+
+        let recipient = match recipient {
+            Some(recipient) => deps.api.addr_validate(&recipient)?,
+            None => info.sender,
+        };
+        // The swapper would execute the callback on itself, passing the internal-only check of
+        // `TransferResult`
+        if recipient == env.contract.address {
+            return Err(ContractError::SwapperAsRecipient {});
+        }
+
+        let legs = match split {
+            Some(split) => self.split_exact_in(deps.as_ref(), &coin_in, &denom_out, split)?,
+            None => {
+                let (index, route) =
+                    self.select_exact_in_route(deps.as_ref(), &env, &coin_in, &denom_out)?;
+                vec![(index, route, coin_in.amount)]
+            }
+        };
 
-        // Check balance of result of swapper and send back result to sender
+        // With multiple legs, min receive can only be checked on the combined output
+        let leg_min_receive = if legs.len() == 1 {
+            min_receive
+        } else {
+            None
+        };
+        let swap_msgs = legs
+            .iter()
+            .map(|(_, route, amount)| {
+                let leg_coin_in = Coin::new(amount.u128(), &coin_in.denom);
+                route.build_exact_in_swap_msg(
+                    &deps.querier,
+                    &env,
+                    &leg_coin_in,
+                    slippage,
+                    leg_min_receive,
+                )
+            })
+            .collect::<ContractResult<Vec<_>>>()?;
+
+        let (denom_in_balance_before, denom_out_balance_before) =
+            self.query_pre_swap_balances(deps.as_ref(), &env, &coin_in, &denom_out)?;
+
+        // Check balance of result of swapper and send result to recipient
         let transfer_msg = CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: env.contract.address.to_string(),
             funds: vec![],
             msg: to_binary(&ExecuteMsg::<R>::TransferResult {
-                recipient: info.sender,
+                recipient: recipient.clone(),
                 denom_in: coin_in.denom.clone(),
                 denom_out: denom_out.clone(),
+                min_receive,
+                denom_in_balance_before,
+                denom_out_balance_before,
+                callback,
             })?,
         });
 
+        let join_legs =
+            |f: fn(&SwapLeg<R>) -> String| legs.iter().map(f).collect::<Vec<_>>().join(",");
+
         Ok(Response::new()
-            .add_message(swap_msg)
+            .add_messages(swap_msgs)
             .add_message(transfer_msg)
             .add_attribute("action", "swap_fn")
+            .add_attribute("route", join_legs(|(_, route, _)| route.to_string()))
+            .add_attribute("route_index", join_legs(|(index, _, _)| index.to_string()))
+            .add_attribute("leg_amounts_in", join_legs(|(_, _, amount)| amount.to_string()))
+            .add_attribute("recipient", recipient)
             .add_attribute("denom_in", coin_in.denom)
             .add_attribute("amount_in", coin_in.amount)
             .add_attribute("denom_out", denom_out)
-            .add_attribute("slippage", slippage.to_string()))
+            .add_attribute("slippage", slippage.to_string())
+            .add_attribute("min_receive", min_receive.unwrap_or_default()))
     }
 
+    fn swap_exact_out(
+        &self,
+        deps: DepsMut<Q>,
+        env: Env,
+        info: MessageInfo,
+        denom_in: String,
+        coin_out: Coin,
+        max_slippage: Decimal,
+    ) -> ContractResult<Response<M>> {
+        self.assert_slippage(deps.as_ref(), max_slippage)?;
+
+        let coin_in_max = assert_only_denom(&info.funds, &denom_in)?;
+
+        let swap_msg = self
+            .find_route(deps.as_ref(), &denom_in, &coin_out.denom)?
+            .route
+            .build_exact_out_swap_msg(&deps.querier, &env, &coin_in_max, &coin_out, max_slippage)?;
+
+        let (denom_in_balance_before, denom_out_balance_before) =
+            self.query_pre_swap_balances(deps.as_ref(), &env, &coin_in_max, &coin_out.denom)?;
+
+        // Send the output back to sender, together with whatever input the swap didn't use
+        let transfer_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            funds: vec![],
+            msg: to_binary(&ExecuteMsg::<R>::TransferResult {
+                recipient: info.sender,
+                denom_in: denom_in.clone(),
+                denom_out: coin_out.denom.clone(),
+                min_receive: Some(coin_out.amount),
+                denom_in_balance_before,
+                denom_out_balance_before,
+                callback: None,
+            })?,
+        });
+
+        Ok(Response::new()
+            .add_message(swap_msg)
+            .add_message(transfer_msg)
+            .add_attribute("action", "swap_exact_out")
+            .add_attribute("denom_in", denom_in)
+            .add_attribute("amount_in_max", coin_in_max.amount)
+            .add_attribute("denom_out", coin_out.denom)
+            .add_attribute("amount_out", coin_out.amount)
+            .add_attribute("max_slippage", max_slippage.to_string()))
+    }
+
+    fn assert_slippage(&self, deps: Deps<Q>, slippage: Decimal) -> ContractResult<()> {
+        let max_slippage = self.max_slippage.load(deps.storage)?;
+        if slippage > max_slippage {
+            return Err(ContractError::MaxSlippageExceeded {
+                max_slippage,
+                slippage,
+            });
+        }
+        Ok(())
+    }
+
+    /// Query the contract's balances of the swap's denoms before the swap is executed, not counting
+    /// `coin_in` which has already been sent to the contract.
+    fn query_pre_swap_balances(
+        &self,
+        deps: Deps<Q>,
+        env: &Env,
+        coin_in: &Coin,
+        denom_out: &str,
+    ) -> ContractResult<(Uint128, Uint128)> {
+        let denom_in_balance =
+            deps.querier.query_balance(env.contract.address.clone(), coin_in.denom.clone())?;
+        let denom_out_balance =
+            deps.querier.query_balance(env.contract.address.clone(), denom_out)?;
+        Ok((denom_in_balance.amount.checked_sub(coin_in.amount)?, denom_out_balance.amount))
+    }
+
+    /// Send what the swap returned (and any unused input) to the recipient
+    #[allow(clippy::too_many_arguments)]
     fn transfer_result(
         &self,
         deps: DepsMut<Q>,
@@ -195,6 +626,10 @@ where
         recipient: Addr,
         denom_in: String,
         denom_out: String,
+        min_receive: Option<Uint128>,
+        denom_in_balance_before: Uint128,
+        denom_out_balance_before: Uint128,
+        callback: Option<Binary>,
     ) -> ContractResult<Response<M>> {
         // Internal callback only
         if info.sender != env.contract.address {
@@ -205,40 +640,218 @@ where
         };
 
         let denom_in_balance =
-            deps.querier.query_balance(env.contract.address.clone(), denom_in)?;
-        let denom_out_balance = deps.querier.query_balance(env.contract.address, denom_out)?;
-
-        let transfer_msg = CosmosMsg::Bank(BankMsg::Send {
-            to_address: recipient.to_string(),
-            amount: vec![denom_in_balance, denom_out_balance]
-                .iter()
-                .filter(|c| !c.amount.is_zero())
-                .cloned()
-                .collect(),
-        });
+            deps.querier.query_balance(env.contract.address.clone(), denom_in.clone())?;
+        let denom_out_balance =
+            deps.querier.query_balance(env.contract.address, denom_out.clone())?;
 
-        Ok(Response::new().add_attribute("action", "transfer_result").add_message(transfer_msg))
+        // Only what this swap returned is sent, not anything else the contract may hold
+        let amount_out = denom_out_balance.amount.checked_sub(denom_out_balance_before)?;
+        if let Some(min_receive) = min_receive {
+            if amount_out < min_receive {
+                return Err(ContractError::MinReceiveNotMet {
+                    denom: denom_out,
+                    min_receive,
+                    received: amount_out,
+                });
+            }
+        }
+
+        // Any input left over (e.g. not spent by an exact-out swap) is refunded alongside the output
+        let refund_amount = denom_in_balance.amount.checked_sub(denom_in_balance_before)?;
+
+        let refund = Coin::new(refund_amount.u128(), denom_in);
+        let output = Coin::new(amount_out.u128(), denom_out);
+
+        let transfer_msgs: Vec<CosmosMsg<M>> = match callback {
+            // Output goes with the callback, the refund (if any) is sent separately
+            Some(msg) => {
+                // Without any output the swapper would just relay an arbitrary message
+                if output.amount.is_zero() {
+                    return Err(ContractError::NoCallbackFunds {
+                        denom: output.denom,
+                    });
+                }
+                let mut msgs = vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: recipient.to_string(),
+                    msg,
+                    funds: vec![output],
+                })];
+                if !refund.amount.is_zero() {
+                    msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                        to_address: recipient.to_string(),
+                        amount: vec![refund],
+                    }));
+                }
+                msgs
+            }
+            None => vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![refund, output].into_iter().filter(|c| !c.amount.is_zero()).collect(),
+            })],
+        };
+
+        Ok(Response::new()
+            .add_attribute("action", "transfer_result")
+            .add_attribute("recipient", recipient)
+            .add_attribute("amount_out", amount_out)
+            .add_attribute("refund_amount", refund_amount)
+            .add_messages(transfer_msgs))
     }
 
     fn set_route(
         &self,
-        deps: DepsMut<Q>,
+        mut deps: DepsMut<Q>,
         sender: Addr,
         denom_in: String,
         denom_out: String,
         route: R,
+        also_reverse: bool,
     ) -> ContractResult<Response<M>> {
         self.owner.assert_owner(deps.storage, &sender)?;
 
-        route.validate(&deps.querier, &denom_in, &denom_out)?;
+        self.validate_and_save_route(deps.branch(), &denom_in, &denom_out, &route)?;
+
+        let mut response = Response::new()
+            .add_attribute("action", "rover/base/set_route")
+            .add_attribute("denom_in", &denom_in)
+            .add_attribute("denom_out", &denom_out)
+            .add_attribute("route", route.to_string());
+
+        if also_reverse {
+            let reverse_route = route.reverse(&denom_in)?;
+            self.validate_and_save_route(deps, &denom_out, &denom_in, &reverse_route)?;
+            response = response.add_attribute("reverse_route", reverse_route.to_string());
+        }
 
-        self.routes.save(deps.storage, (denom_in.clone(), denom_out.clone()), &route)?;
+        Ok(response)
+    }
+
+    fn set_routes(
+        &self,
+        mut deps: DepsMut<Q>,
+        sender: Addr,
+        routes: Vec<PairRoute<R>>,
+    ) -> ContractResult<Response<M>> {
+        self.owner.assert_owner(deps.storage, &sender)?;
+
+        let mut events = vec![];
+        for PairRoute {
+            denom_in,
+            denom_out,
+            route,
+        } in routes
+        {
+            self.validate_and_save_route(deps.branch(), &denom_in, &denom_out, &route)?;
+
+            events.push(
+                Event::new("set_route")
+                    .add_attribute("denom_in", denom_in)
+                    .add_attribute("denom_out", denom_out)
+                    .add_attribute("route", route.to_string()),
+            );
+        }
 
         Ok(Response::new()
-            .add_attribute("action", "rover/base/set_route")
+            .add_attribute("action", "rover/base/set_routes")
+            .add_attribute("count", events.len().to_string())
+            .add_events(events))
+    }
+
+    fn validate_and_save_route(
+        &self,
+        deps: DepsMut<Q>,
+        denom_in: &str,
+        denom_out: &str,
+        route: &R,
+    ) -> ContractResult<()> {
+        route.validate(&deps.querier, denom_in, denom_out)?;
+
+        let key = (denom_in.to_string(), denom_out.to_string());
+        if !self.routes.has(deps.storage, key.clone()) {
+            let count = self.route_count.may_load(deps.storage)?.unwrap_or_default();
+            self.route_count.save(deps.storage, &(count + 1))?;
+        }
+        self.routes.save(deps.storage, key, route)?;
+        Ok(())
+    }
+
+    fn remove_route(
+        &self,
+        deps: DepsMut<Q>,
+        sender: Addr,
+        denom_in: String,
+        denom_out: String,
+    ) -> ContractResult<Response<M>> {
+        self.owner.assert_owner(deps.storage, &sender)?;
+
+        let key = (denom_in.clone(), denom_out.clone());
+        let has_route = self.routes.has(deps.storage, key.clone());
+        let has_candidates = self.route_candidates.has(deps.storage, key.clone());
+        if !has_route && !has_candidates {
+            return Err(ContractError::NoRouteFound {
+                denom_in,
+                denom_out,
+            });
+        }
+
+        if has_route {
+            self.routes.remove(deps.storage, key.clone());
+            let count = self.route_count.may_load(deps.storage)?.unwrap_or_default();
+            self.route_count.save(deps.storage, &count.saturating_sub(1))?;
+        }
+        self.route_candidates.remove(deps.storage, key);
+
+        Ok(Response::new()
+            .add_attribute("action", "rover/base/remove_route")
+            .add_attribute("denom_in", denom_in)
+            .add_attribute("denom_out", denom_out))
+    }
+
+    fn set_route_candidates(
+        &self,
+        deps: DepsMut<Q>,
+        sender: Addr,
+        denom_in: String,
+        denom_out: String,
+        routes: Vec<R>,
+    ) -> ContractResult<Response<M>> {
+        self.owner.assert_owner(deps.storage, &sender)?;
+
+        for route in &routes {
+            route.validate(&deps.querier, &denom_in, &denom_out)?;
+        }
+
+        let key = (denom_in.clone(), denom_out.clone());
+        if routes.is_empty() {
+            self.route_candidates.remove(deps.storage, key);
+        } else {
+            self.route_candidates.save(deps.storage, key, &routes)?;
+        }
+
+        Ok(Response::new()
+            .add_attribute("action", "rover/base/set_route_candidates")
             .add_attribute("denom_in", denom_in)
             .add_attribute("denom_out", denom_out)
-            .add_attribute("route", route.to_string()))
+            .add_attribute(
+                "routes",
+                routes.iter().map(|route| route.to_string()).collect::<Vec<_>>().join(","),
+            ))
+    }
+
+    fn update_max_slippage(
+        &self,
+        deps: DepsMut<Q>,
+        info: MessageInfo,
+        max_slippage: Decimal,
+    ) -> ContractResult<Response<M>> {
+        self.owner.assert_owner(deps.storage, &info.sender)?;
+
+        assert_max_slippage(max_slippage)?;
+        self.max_slippage.save(deps.storage, &max_slippage)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "rover/base/update_max_slippage")
+            .add_attribute("max_slippage", max_slippage.to_string()))
     }
 
     fn update_owner(
@@ -250,3 +863,74 @@ where
         Ok(self.owner.update(deps, info, update)?)
     }
 }
+
+fn assert_max_slippage(max_slippage: Decimal) -> ContractResult<()> {
+    if max_slippage >= Decimal::one() {
+        return Err(ContractError::InvalidMaxSlippage {
+            max_slippage,
+        });
+    }
+    Ok(())
+}
+
+/// The funds sent must be exactly the swap's input, so nothing else held by the contract is spent
+fn assert_exact_funds(funds: &[Coin], coin_in: &Coin) -> ContractResult<()> {
+    match funds {
+        [coin] if coin == coin_in => Ok(()),
+        _ if !funds.iter().any(|c| c.denom == coin_in.denom) => Err(ContractError::NoFundsSent {
+            denom: coin_in.denom.clone(),
+        }),
+        _ => Err(ContractError::UnexpectedFunds {
+            expected: coin_in.clone(),
+        }),
+    }
+}
+
+/// Assert that funds sent are a single non-zero coin of the given denom, and return it
+fn assert_only_denom(funds: &[Coin], denom: &str) -> ContractResult<Coin> {
+    match funds {
+        [coin] if coin.denom == denom && !coin.amount.is_zero() => Ok(coin.clone()),
+        _ if !funds.iter().any(|c| c.denom == denom && !c.amount.is_zero()) => {
+            Err(ContractError::NoFundsSent {
+                denom: denom.to_string(),
+            })
+        }
+        _ => Err(ContractError::UnexpectedDenoms {
+            denom: denom.to_string(),
+        }),
+    }
+}
+
+/// Split an amount by weights. Rounding leftovers go to the last leg with a non-zero weight.
+fn split_by_weights(
+    amount: Uint128,
+    weights: &[Decimal],
+    routes_count: usize,
+) -> ContractResult<Vec<Uint128>> {
+    if weights.len() != routes_count {
+        return Err(ContractError::InvalidSplit {
+            reason: format!("expected {routes_count} weights, got {}", weights.len()),
+        });
+    }
+
+    let total_weight =
+        weights.iter().try_fold(Decimal::zero(), |total, weight| total.checked_add(*weight))?;
+    if total_weight.is_zero() {
+        return Err(ContractError::InvalidSplit {
+            reason: "weights must not all be zero".to_string(),
+        });
+    }
+
+    let mut amounts = weights
+        .iter()
+        .map(|weight| amount.multiply_ratio(weight.atomics(), total_weight.atomics()))
+        .collect::<Vec<_>>();
+
+    let allocated = amounts.iter().fold(Uint128::zero(), |total, amount| total + amount);
+    let remainder = amount.checked_sub(allocated)?;
+    if let Some(last) = weights.iter().rposition(|weight| !weight.is_zero()) {
+        amounts[last] += remainder;
+    }
+
+    Ok(amounts)
+}
@@ -1,4 +1,7 @@
-use cosmwasm_std::{CheckedMultiplyRatioError, DecimalRangeExceeded, OverflowError, StdError};
+use cosmwasm_std::{
+    CheckedMultiplyRatioError, Coin, Decimal, DecimalRangeExceeded, OverflowError, StdError,
+    Uint128,
+};
 use mars_owner::OwnerError;
 use thiserror::Error;
 
@@ -30,6 +33,58 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("Received {received} {denom}, less than the minimum of {min_receive}")]
+    MinReceiveNotMet {
+        denom: String,
+        min_receive: Uint128,
+        received: Uint128,
+    },
+
+    #[error("Max slippage must be less than 1, got {max_slippage}")]
+    InvalidMaxSlippage {
+        max_slippage: Decimal,
+    },
+
+    #[error("Slippage {slippage} exceeds the max slippage of {max_slippage}")]
+    MaxSlippageExceeded {
+        max_slippage: Decimal,
+        slippage: Decimal,
+    },
+
+    #[error("No route found from {denom_in} to {denom_out}")]
+    NoRouteFound {
+        denom_in: String,
+        denom_out: String,
+    },
+
+    #[error("Invalid split: {reason}")]
+    InvalidSplit {
+        reason: String,
+    },
+
+    #[error("No {denom:?} funds sent for the swap")]
+    NoFundsSent {
+        denom: String,
+    },
+
+    #[error("No {denom:?} output to attach to the callback")]
+    NoCallbackFunds {
+        denom: String,
+    },
+
+    #[error("Only {denom:?} can be sent for the swap")]
+    UnexpectedDenoms {
+        denom: String,
+    },
+
+    #[error("The swapper can't be the recipient of a swap")]
+    SwapperAsRecipient {},
+
+    #[error("Expected exactly {expected} to be sent for the swap")]
+    UnexpectedFunds {
+        expected: Coin,
+    },
+
     #[error("{user:?} is not authorized to {action:?}")]
     Unauthorized {
         user: String,
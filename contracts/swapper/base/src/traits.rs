@@ -1,7 +1,9 @@
 use std::fmt::{Debug, Display};
 
-use cosmwasm_std::{Coin, CosmosMsg, CustomMsg, CustomQuery, Decimal, Env, QuerierWrapper};
-use mars_swapper::msgs::EstimateExactInSwapResponse;
+use cosmwasm_std::{
+    Coin, CosmosMsg, CustomMsg, CustomQuery, Decimal, Env, QuerierWrapper, Uint128,
+};
+use mars_swapper::msgs::{EstimateExactInSwapResponse, EstimateExactOutSwapResponse};
 use schemars::JsonSchema;
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -21,13 +23,23 @@ where
         denom_out: &str,
     ) -> ContractResult<()>;
 
-    /// Build a message for executing the trade, given an input denom and amount
+    /// Join routes, each one starting at the output denom of the previous one, into a single route
+    fn concat(routes: &[Self]) -> ContractResult<Self>;
+
+    /// Derive the route swapping in the opposite direction, given the input denom of this route
+    fn reverse(&self, denom_in: &str) -> ContractResult<Self>;
+
+    /// Build a message for executing the trade, given an input denom and amount.
+    ///
+    /// If `min_receive` is provided, it is used as the minimum output instead of the one derived
+    /// from `slippage`.
     fn build_exact_in_swap_msg(
         &self,
         querier: &QuerierWrapper<Q>,
         env: &Env,
         coin_in: &Coin,
         slippage: Decimal,
+        min_receive: Option<Uint128>,
     ) -> ContractResult<CosmosMsg<M>>;
 
     /// Query to get the estimate result of a swap
@@ -37,4 +49,24 @@ where
         env: &Env,
         coin_in: &Coin,
     ) -> ContractResult<EstimateExactInSwapResponse>;
+
+    /// Build a message for executing the trade, given the exact output coin and the max input
+    /// that can be spent, which is also capped by `max_slippage`
+    fn build_exact_out_swap_msg(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        env: &Env,
+        coin_in_max: &Coin,
+        coin_out: &Coin,
+        max_slippage: Decimal,
+    ) -> ContractResult<CosmosMsg<M>>;
+
+    /// Query to get the estimated input amount needed for an exact-out swap
+    fn estimate_exact_out_swap(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        env: &Env,
+        denom_in: &str,
+        coin_out: &Coin,
+    ) -> ContractResult<EstimateExactOutSwapResponse>;
 }
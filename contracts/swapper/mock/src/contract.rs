@@ -25,16 +25,32 @@ pub fn execute(
 ) -> StdResult<Response> {
     match msg {
         ExecuteMsg::UpdateOwner(_) => unimplemented!("not implemented"),
+        ExecuteMsg::UpdateMaxSlippage {
+            ..
+        } => unimplemented!("not implemented"),
         ExecuteMsg::SetRoute {
             ..
         } => unimplemented!("not implemented"),
+        ExecuteMsg::SetRoutes {
+            ..
+        } => unimplemented!("not implemented"),
+        ExecuteMsg::RemoveRoute {
+            ..
+        } => unimplemented!("not implemented"),
+        ExecuteMsg::SetRouteCandidates {
+            ..
+        } => unimplemented!("not implemented"),
         ExecuteMsg::TransferResult {
             ..
         } => unimplemented!("not implemented"),
+        ExecuteMsg::SwapExactOut {
+            ..
+        } => unimplemented!("not implemented"),
         ExecuteMsg::SwapExactIn {
             coin_in,
             denom_out,
             slippage,
+            ..
         } => swap_exact_in(deps, env, info, coin_in, denom_out, slippage),
     }
 }
@@ -45,15 +61,27 @@ pub fn query(_deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Owner {
             ..
         } => unimplemented!("not implemented"),
+        QueryMsg::MaxSlippage {
+            ..
+        } => unimplemented!("not implemented"),
         QueryMsg::Route {
             ..
         } => unimplemented!("not implemented"),
+        QueryMsg::FindRoute {
+            ..
+        } => unimplemented!("not implemented"),
+        QueryMsg::RouteCandidates {
+            ..
+        } => unimplemented!("not implemented"),
         QueryMsg::Routes {
             ..
         } => unimplemented!("not implemented"),
         QueryMsg::EstimateExactInSwap {
             ..
         } => to_binary(&estimate_exact_in_swap()),
+        QueryMsg::EstimateExactOutSwap {
+            ..
+        } => unimplemented!("not implemented"),
     }
 }
 
@@ -1,5 +1,5 @@
 use cosmwasm_schema::write_api;
-use mars_swapper::msgs::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use mars_swapper::msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use mars_swapper_osmosis::route::OsmosisRoute;
 
 fn main() {
@@ -7,5 +7,6 @@ fn main() {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg<OsmosisRoute>,
         query: QueryMsg,
+        migrate: MigrateMsg,
     }
 }
@@ -1,6 +1,6 @@
 use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response};
 use cw2::set_contract_version;
-use mars_swapper::msgs::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use mars_swapper::msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use mars_swapper_base::{ContractResult, SwapBase};
 
 use crate::route::OsmosisRoute;
@@ -36,3 +36,9 @@ pub fn execute(
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     OsmosisSwap::default().query(deps, env, msg)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> ContractResult<Response> {
+    set_contract_version(deps.storage, format!("crates.io:{CONTRACT_NAME}"), CONTRACT_VERSION)?;
+    OsmosisSwap::default().migrate(deps, msg)
+}
@@ -5,10 +5,11 @@ use cosmwasm_std::{
     BlockInfo, Coin, CosmosMsg, Decimal, Empty, Env, Fraction, QuerierWrapper, Uint128,
 };
 use mars_osmosis::helpers::{has_denom, query_arithmetic_twap_price, query_pool};
-use mars_swapper::EstimateExactInSwapResponse;
+use mars_swapper::{EstimateExactInSwapResponse, EstimateExactOutSwapResponse};
 use mars_swapper_base::{ContractError, ContractResult, Route};
 use osmosis_std::types::osmosis::{
-    gamm::v1beta1::MsgSwapExactAmountIn, poolmanager::v1beta1::SwapAmountInRoute,
+    gamm::v1beta1::{MsgSwapExactAmountIn, MsgSwapExactAmountOut},
+    poolmanager::v1beta1::{SwapAmountInRoute, SwapAmountOutRoute},
 };
 
 use crate::helpers::hashset;
@@ -103,6 +104,30 @@ impl Route<Empty, Empty> for OsmosisRoute {
         Ok(())
     }
 
+    fn concat(routes: &[Self]) -> ContractResult<Self> {
+        Ok(OsmosisRoute(routes.iter().flat_map(|route| route.0.clone()).collect()))
+    }
+
+    /// Reverse the steps, each one outputting the denom the original step takes as input
+    fn reverse(&self, denom_in: &str) -> ContractResult<Self> {
+        if self.0.is_empty() {
+            return Err(ContractError::InvalidRoute {
+                reason: "the route must contain at least one step".to_string(),
+            });
+        }
+
+        Ok(OsmosisRoute(
+            to_exact_out_routes(denom_in, &self.0)
+                .into_iter()
+                .rev()
+                .map(|step| SwapAmountInRoute {
+                    pool_id: step.pool_id,
+                    token_out_denom: step.token_in_denom,
+                })
+                .collect(),
+        ))
+    }
+
     /// Build a CosmosMsg that swaps given an input denom and amount
     fn build_exact_in_swap_msg(
         &self,
@@ -110,6 +135,7 @@ impl Route<Empty, Empty> for OsmosisRoute {
         env: &Env,
         coin_in: &Coin,
         slippage: Decimal,
+        min_receive: Option<Uint128>,
     ) -> ContractResult<CosmosMsg> {
         let steps = &self.0;
 
@@ -117,8 +143,13 @@ impl Route<Empty, Empty> for OsmosisRoute {
             reason: "the route must contain at least one step".to_string(),
         })?;
 
-        let out_amount = query_out_amount(querier, &env.block, coin_in, steps)?;
-        let min_out_amount = (Decimal::one() - slippage) * out_amount;
+        let min_out_amount = match min_receive {
+            Some(min_receive) => min_receive,
+            None => {
+                let out_amount = query_out_amount(querier, &env.block, coin_in, steps)?;
+                (Decimal::one() - slippage) * out_amount
+            }
+        };
 
         let swap_msg: CosmosMsg = MsgSwapExactAmountIn {
             sender: env.contract.address.to_string(),
@@ -144,6 +175,71 @@ impl Route<Empty, Empty> for OsmosisRoute {
             amount: out_amount,
         })
     }
+
+    /// Build a CosmosMsg that swaps given the max input and the exact output amount
+    fn build_exact_out_swap_msg(
+        &self,
+        querier: &QuerierWrapper,
+        env: &Env,
+        coin_in_max: &Coin,
+        coin_out: &Coin,
+        max_slippage: Decimal,
+    ) -> ContractResult<CosmosMsg> {
+        let steps = &self.0;
+
+        steps.first().ok_or(ContractError::InvalidRoute {
+            reason: "the route must contain at least one step".to_string(),
+        })?;
+
+        let denom_in = &coin_in_max.denom;
+        let in_amount = query_in_amount(querier, &env.block, denom_in, coin_out, steps)?;
+        // Never spend more than what was sent, even if the slippage allows for it
+        let max_in_amount = ((Decimal::one() + max_slippage) * in_amount).min(coin_in_max.amount);
+
+        let swap_msg: CosmosMsg = MsgSwapExactAmountOut {
+            sender: env.contract.address.to_string(),
+            routes: to_exact_out_routes(denom_in, steps),
+            token_in_max_amount: max_in_amount.to_string(),
+            token_out: Some(osmosis_std::types::cosmos::base::v1beta1::Coin {
+                denom: coin_out.denom.clone(),
+                amount: coin_out.amount.to_string(),
+            }),
+        }
+        .into();
+        Ok(swap_msg)
+    }
+
+    fn estimate_exact_out_swap(
+        &self,
+        querier: &QuerierWrapper,
+        env: &Env,
+        denom_in: &str,
+        coin_out: &Coin,
+    ) -> ContractResult<EstimateExactOutSwapResponse> {
+        let in_amount = query_in_amount(querier, &env.block, denom_in, coin_out, &self.0)?;
+        Ok(EstimateExactOutSwapResponse {
+            amount: in_amount,
+        })
+    }
+}
+
+/// Convert exact-in steps (pool + output denom) into exact-out steps (pool + input denom).
+///
+/// Both are ordered from the first pool to the last, so the input denom of each step is the
+/// output denom of the previous one.
+fn to_exact_out_routes(denom_in: &str, steps: &[SwapAmountInRoute]) -> Vec<SwapAmountOutRoute> {
+    let mut token_in_denom = denom_in.to_string();
+    steps
+        .iter()
+        .map(|step| {
+            let route = SwapAmountOutRoute {
+                pool_id: step.pool_id,
+                token_in_denom: token_in_denom.clone(),
+            };
+            token_in_denom = step.token_out_denom.clone();
+            route
+        })
+        .collect()
 }
 
 /// Query how much amount of denom_out we get for denom_in.
@@ -160,10 +256,37 @@ fn query_out_amount(
     coin_in: &Coin,
     steps: &[SwapAmountInRoute],
 ) -> ContractResult<Uint128> {
+    let price = query_route_price(querier, block, &coin_in.denom, steps)?;
+    let out_amount =
+        coin_in.amount.checked_multiply_ratio(price.numerator(), price.denominator())?;
+    Ok(out_amount)
+}
+
+/// Query how much amount of denom_in is needed to get coin_out, i.e. the inverse of `query_out_amount`.
+fn query_in_amount(
+    querier: &QuerierWrapper,
+    block: &BlockInfo,
+    denom_in: &str,
+    coin_out: &Coin,
+    steps: &[SwapAmountInRoute],
+) -> ContractResult<Uint128> {
+    let price = query_route_price(querier, block, denom_in, steps)?;
+    let in_amount =
+        coin_out.amount.checked_multiply_ratio(price.denominator(), price.numerator())?;
+    Ok(in_amount)
+}
+
+/// Price of denom_in quoted in the route's final output denom, multiplied across all steps.
+fn query_route_price(
+    querier: &QuerierWrapper,
+    block: &BlockInfo,
+    denom_in: &str,
+    steps: &[SwapAmountInRoute],
+) -> ContractResult<Decimal> {
     let start_time = block.time.seconds() - TWAP_WINDOW_SIZE_SECONDS;
 
     let mut price = Decimal::one();
-    let mut denom_in = coin_in.denom.clone();
+    let mut denom_in = denom_in.to_string();
     for step in steps {
         let step_price = query_arithmetic_twap_price(
             querier,
@@ -176,7 +299,5 @@ fn query_out_amount(
         denom_in = step.token_out_denom.clone();
     }
 
-    let out_amount =
-        coin_in.amount.checked_multiply_ratio(price.numerator(), price.denominator())?;
-    Ok(out_amount)
+    Ok(price)
 }
@@ -1,14 +1,17 @@
 use std::{fmt::Display, str::FromStr};
 
-use cosmwasm_std::{Coin, Decimal, Uint128};
-use mars_swapper::msgs::InstantiateMsg;
-use osmosis_std::types::osmosis::{
-    gamm::v1beta1::{MsgSwapExactAmountIn, MsgSwapExactAmountInResponse},
-    poolmanager::v1beta1::SwapAmountInRoute,
+use cosmwasm_std::{to_vec, Coin, Decimal, Uint128};
+use mars_swapper::msgs::{InstantiateMsg, MigrateMsg};
+use osmosis_std::types::{
+    cosmwasm::wasm::v1::{MsgMigrateContract, MsgMigrateContractResponse},
+    osmosis::{
+        gamm::v1beta1::{MsgSwapExactAmountIn, MsgSwapExactAmountInResponse},
+        poolmanager::v1beta1::SwapAmountInRoute,
+    },
 };
 use osmosis_test_tube::{
     cosmrs::proto::cosmos::bank::v1beta1::QueryBalanceRequest, Account, Bank, ExecuteResponse,
-    Gamm, OsmosisTestApp, Runner, RunnerError, SigningAccount, Wasm,
+    Gamm, Module, OsmosisTestApp, Runner, RunnerError, RunnerExecuteResult, SigningAccount, Wasm,
 };
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -28,8 +31,9 @@ pub fn instantiate_contract(wasm: &Wasm<OsmosisTestApp>, owner: &SigningAccount)
         code_id,
         &InstantiateMsg {
             owner: owner.address(),
+            max_slippage: Decimal::percent(10),
         },
-        None,
+        Some(&owner.address()),
         Some("swapper-osmosis-contract"),
         &[],
         owner,
@@ -39,6 +43,29 @@ pub fn instantiate_contract(wasm: &Wasm<OsmosisTestApp>, owner: &SigningAccount)
     .address
 }
 
+/// Migrate the contract to a freshly stored code of the same wasm file
+pub fn migrate_contract(
+    app: &OsmosisTestApp,
+    contract_addr: &str,
+    msg: &MigrateMsg,
+    admin: &SigningAccount,
+) -> RunnerExecuteResult<MsgMigrateContractResponse> {
+    let wasm = Wasm::new(app);
+    let wasm_byte_code = std::fs::read(wasm_file()).unwrap();
+    let code_id = wasm.store_code(&wasm_byte_code, None, admin).unwrap().data.code_id;
+
+    app.execute(
+        MsgMigrateContract {
+            sender: admin.address(),
+            contract: contract_addr.to_string(),
+            code_id,
+            msg: to_vec(msg).unwrap(),
+        },
+        MsgMigrateContract::TYPE_URL,
+        admin,
+    )
+}
+
 /// Every execution creates new block and block timestamp will +5 secs from last block
 /// (see https://github.com/osmosis-labs/osmosis-rust/issues/53#issuecomment-1311451418).
 ///
@@ -0,0 +1,185 @@
+use cosmwasm_std::{coin, from_binary, Addr, CosmosMsg, Decimal, Empty, Uint128, WasmMsg};
+use mars_swapper::{adapter::Swapper, msgs::ExecuteMsg};
+use mars_swapper_osmosis::route::OsmosisRoute;
+use osmosis_std::types::{
+    cosmwasm::wasm::v1::MsgExecuteContractResponse,
+    osmosis::poolmanager::v1beta1::SwapAmountInRoute,
+};
+use osmosis_test_tube::{
+    Account, Bank, Gamm, Module, OsmosisTestApp, RunnerExecuteResult, SigningAccount, Wasm,
+};
+
+use crate::helpers::{
+    assert_err, instantiate_contract, query_balance, swap_to_create_twap_records,
+};
+
+pub mod helpers;
+
+#[test]
+fn swap_exact_in_to_recipient() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let bank = Bank::new(&app);
+
+    let (signer, contract_addr, _) = setup(&app);
+    let recipient = app.init_account(&[]).unwrap();
+
+    let swapper = Swapper::new(Addr::unchecked(&contract_addr));
+    let msg = swapper
+        .swap_exact_in_to_recipient_msg(
+            &coin(10_000, "umars"),
+            "uosmo",
+            Decimal::percent(6),
+            &Addr::unchecked(recipient.address()),
+        )
+        .unwrap();
+    execute(&wasm, msg, &signer).unwrap();
+
+    let osmo_balance = query_balance(&bank, &recipient.address(), "uosmo");
+    assert_eq!(osmo_balance, 2470);
+}
+
+#[test]
+fn swap_exact_in_with_min_receive() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let (signer, contract_addr, _) = setup(&app);
+
+    let swapper = Swapper::new(Addr::unchecked(&contract_addr));
+    let msg = swapper
+        .swap_exact_in_with_min_receive_msg(
+            &coin(10_000, "umars"),
+            "uosmo",
+            Decimal::percent(6),
+            Uint128::new(2471),
+        )
+        .unwrap();
+    let res_err = execute(&wasm, msg, &signer).unwrap_err();
+    assert_err(res_err, "uosmo token is lesser than min amount");
+
+    let msg = swapper
+        .swap_exact_in_with_min_receive_msg(
+            &coin(10_000, "umars"),
+            "uosmo",
+            Decimal::percent(6),
+            Uint128::new(2470),
+        )
+        .unwrap();
+    execute(&wasm, msg, &signer).unwrap();
+}
+
+#[test]
+fn swap_exact_in_with_callback() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let bank = Bank::new(&app);
+
+    let (signer, contract_addr, pool_mars_osmo) = setup(&app);
+    let user = app.init_account(&[coin(10_000, "umars"), coin(10_000_000, "uosmo")]).unwrap();
+
+    // a second swapper swaps the output of the first one back, on behalf of the user
+    let callback_contract_addr = instantiate_contract(&wasm, &signer);
+    wasm.execute(
+        &callback_contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "uosmo".to_string(),
+            denom_out: "umars".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_mars_osmo,
+                token_out_denom: "umars".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    let swapper = Swapper::new(Addr::unchecked(&contract_addr));
+    let msg = swapper
+        .swap_exact_in_with_callback_msg(
+            &coin(10_000, "umars"),
+            "uosmo",
+            Decimal::percent(6),
+            &Addr::unchecked(&callback_contract_addr),
+            &ExecuteMsg::<Empty>::SwapExactIn {
+                coin_in: coin(2470, "uosmo"),
+                denom_out: "umars".to_string(),
+                slippage: Decimal::percent(6),
+                min_receive: None,
+                recipient: Some(user.address()),
+                callback: None,
+                split: None,
+            },
+        )
+        .unwrap();
+    execute(&wasm, msg, &user).unwrap();
+
+    // the callback ran with the output of the first swap attached
+    let mars_balance = query_balance(&bank, &user.address(), "umars");
+    assert!(mars_balance > 0 && mars_balance < 10_000);
+
+    // Assert no tokens in either contract left over
+    for addr in [&contract_addr, &callback_contract_addr] {
+        assert_eq!(query_balance(&bank, addr, "uosmo"), 0);
+        assert_eq!(query_balance(&bank, addr, "umars"), 0);
+    }
+}
+
+/// Create a umars/uosmo pool and a swapper with a route for swapping umars to uosmo
+fn setup(app: &OsmosisTestApp) -> (SigningAccount, String, u64) {
+    let wasm = Wasm::new(app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(app, &signer, pool_mars_osmo, coin(10u128, "umars"), "uosmo");
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_mars_osmo,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    (signer, contract_addr, pool_mars_osmo)
+}
+
+/// Execute a message generated by the `Swapper` adapter
+fn execute(
+    wasm: &Wasm<OsmosisTestApp>,
+    msg: CosmosMsg,
+    signer: &SigningAccount,
+) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+    match msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            let msg: ExecuteMsg<OsmosisRoute> = from_binary(&msg).unwrap();
+            wasm.execute(&contract_addr, &msg, &funds, signer)
+        }
+        _ => panic!("the adapter should generate a wasm execute message"),
+    }
+}
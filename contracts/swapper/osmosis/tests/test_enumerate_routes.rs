@@ -3,7 +3,7 @@ extern crate core;
 use std::collections::HashMap;
 
 use cosmwasm_std::coin;
-use mars_swapper::msgs::{ExecuteMsg, QueryMsg, RouteResponse};
+use mars_swapper::msgs::{ExecuteMsg, QueryMsg, RouteResponse, RoutesResponse};
 use mars_swapper_osmosis::route::OsmosisRoute;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
 use osmosis_test_tube::{Gamm, Module, OsmosisTestApp, SigningAccount, Wasm};
@@ -35,6 +35,7 @@ fn enumerating_routes() {
             denom_in: "uatom".to_string(),
             denom_out: "umars".to_string(),
             route: routes.get(&("uatom", "umars")).unwrap().clone(),
+            also_reverse: false,
         },
         &[],
         &signer,
@@ -47,6 +48,7 @@ fn enumerating_routes() {
             denom_in: "uatom".to_string(),
             denom_out: "uusdc".to_string(),
             route: routes.get(&("uatom", "uusdc")).unwrap().clone(),
+            also_reverse: false,
         },
         &[],
         &signer,
@@ -59,6 +61,7 @@ fn enumerating_routes() {
             denom_in: "uosmo".to_string(),
             denom_out: "umars".to_string(),
             route: routes.get(&("uosmo", "umars")).unwrap().clone(),
+            also_reverse: false,
         },
         &[],
         &signer,
@@ -84,7 +87,7 @@ fn enumerating_routes() {
         },
     ];
 
-    let res: Vec<RouteResponse<OsmosisRoute>> = wasm
+    let res: RoutesResponse<OsmosisRoute> = wasm
         .query(
             &contract_addr,
             &QueryMsg::Routes {
@@ -93,9 +96,11 @@ fn enumerating_routes() {
             },
         )
         .unwrap();
-    assert_eq!(res, expected);
+    assert_eq!(res.data, expected);
+    assert_eq!(res.next_start_after, None);
+    assert_eq!(res.total_count, 3);
 
-    let res: Vec<RouteResponse<OsmosisRoute>> = wasm
+    let res: RoutesResponse<OsmosisRoute> = wasm
         .query(
             &contract_addr,
             &QueryMsg::Routes {
@@ -104,9 +109,11 @@ fn enumerating_routes() {
             },
         )
         .unwrap();
-    assert_eq!(res, expected[..1]);
+    assert_eq!(res.data, expected[..1]);
+    assert_eq!(res.next_start_after, Some(("uatom".to_string(), "umars".to_string())));
+    assert_eq!(res.total_count, 3);
 
-    let res: Vec<RouteResponse<OsmosisRoute>> = wasm
+    let res: RoutesResponse<OsmosisRoute> = wasm
         .query(
             &contract_addr,
             &QueryMsg::Routes {
@@ -115,7 +122,45 @@ fn enumerating_routes() {
             },
         )
         .unwrap();
-    assert_eq!(res, expected[1..]);
+    assert_eq!(res.data, expected[1..]);
+    assert_eq!(res.next_start_after, None);
+    assert_eq!(res.total_count, 3);
+
+    // overwriting a route doesn't change the count, removing one does
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "uatom".to_string(),
+            denom_out: "umars".to_string(),
+            route: routes.get(&("uatom", "umars")).unwrap().clone(),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::<OsmosisRoute>::RemoveRoute {
+            denom_in: "uatom".to_string(),
+            denom_out: "uusdc".to_string(),
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    let res: RoutesResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::Routes {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.data, vec![expected[0].clone(), expected[2].clone()]);
+    assert_eq!(res.total_count, 2);
 }
 
 fn create_pools_and_routes(
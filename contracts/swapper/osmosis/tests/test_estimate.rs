@@ -1,5 +1,8 @@
-use cosmwasm_std::{coin, Uint128};
-use mars_swapper::msgs::{EstimateExactInSwapResponse, ExecuteMsg, QueryMsg};
+use cosmwasm_std::{coin, Fraction, Uint128};
+use mars_swapper::msgs::{
+    EstimateExactInSwapResponse, EstimateExactOutSwapResponse, ExecuteMsg, QueryMsg,
+};
+use mars_swapper_base::ContractError;
 use mars_swapper_osmosis::route::OsmosisRoute;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
 use osmosis_test_tube::{Gamm, Module, OsmosisTestApp, RunnerResult, Wasm};
@@ -26,7 +29,13 @@ fn error_on_route_not_found() {
         },
     );
 
-    assert_err(res.unwrap_err(), "swapper_osmosis::route::OsmosisRoute not found");
+    assert_err(
+        res.unwrap_err(),
+        ContractError::NoRouteFound {
+            denom_in: "jake".to_string(),
+            denom_out: "mars".to_string(),
+        },
+    );
 }
 
 #[test]
@@ -58,6 +67,7 @@ fn estimate_swap_one_step() {
                 pool_id: pool_atom_osmo,
                 token_out_denom: "uatom".to_string(),
             }]),
+            also_reverse: false,
         },
         &[],
         &signer,
@@ -130,6 +140,7 @@ fn estimate_swap_multi_step() {
                     token_out_denom: "umars".to_string(),
                 },
             ]),
+            also_reverse: false,
         },
         &[],
         &signer,
@@ -151,6 +162,7 @@ fn estimate_swap_multi_step() {
                     token_out_denom: "uusdc".to_string(),
                 },
             ]),
+            also_reverse: false,
         },
         &[],
         &signer,
@@ -181,3 +193,58 @@ fn estimate_swap_multi_step() {
         .unwrap();
     assert_eq!(res.amount, expected_output);
 }
+
+#[test]
+fn estimate_exact_out_swap_one_step() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uatom"), coin(1_000_000_000_000, "uosmo")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_atom_osmo = gamm
+        .create_basic_pool(&[coin(1_500_000, "uatom"), coin(6_000_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(&app, &signer, pool_atom_osmo, coin(10u128, "uatom"), "uosmo");
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "uosmo".to_string(),
+            denom_out: "uatom".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_atom_osmo,
+                token_out_denom: "uatom".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    // 1 uosmo = 0.25 uatom => 1000 uatom needs ~4000 uosmo
+    let coin_out_amount = Uint128::from(1000u128);
+    let uosmo_price = query_price_from_pool(&gamm, pool_atom_osmo, "uosmo");
+    let expected_input = coin_out_amount
+        .checked_multiply_ratio(uosmo_price.denominator(), uosmo_price.numerator())
+        .unwrap();
+
+    let res: EstimateExactOutSwapResponse = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::EstimateExactOutSwap {
+                denom_in: "uosmo".to_string(),
+                coin_out: coin(coin_out_amount.u128(), "uatom"),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.amount, expected_input);
+}
@@ -0,0 +1,188 @@
+use cosmwasm_std::{coin, Uint128};
+use mars_swapper::msgs::{EstimateExactInSwapResponse, ExecuteMsg, FindRouteResponse, QueryMsg};
+use mars_swapper_base::ContractError;
+use mars_swapper_osmosis::route::OsmosisRoute;
+use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
+use osmosis_test_tube::{Gamm, Module, OsmosisTestApp, RunnerResult, SigningAccount, Wasm};
+
+use crate::helpers::{
+    assert_err, instantiate_contract, query_price_from_pool, swap_to_create_twap_records,
+};
+
+pub mod helpers;
+
+#[test]
+fn direct_route_is_preferred() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let signer = init_signer(&app);
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+    let (pool_atom_osmo, pool_osmo_usdc) = create_pools(&app, &signer);
+    set_routes(&wasm, &contract_addr, &signer, pool_atom_osmo, pool_osmo_usdc);
+
+    let res: FindRouteResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::FindRoute {
+                denom_in: "uatom".to_string(),
+                denom_out: "uosmo".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.denoms, vec!["uatom".to_string(), "uosmo".to_string()]);
+    assert_eq!(
+        res.route,
+        OsmosisRoute(vec![SwapAmountInRoute {
+            pool_id: pool_atom_osmo,
+            token_out_denom: "uosmo".to_string(),
+        }])
+    );
+}
+
+#[test]
+fn route_composed_from_stored_routes() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let signer = init_signer(&app);
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+    let (pool_atom_osmo, pool_osmo_usdc) = create_pools(&app, &signer);
+    set_routes(&wasm, &contract_addr, &signer, pool_atom_osmo, pool_osmo_usdc);
+
+    let res: FindRouteResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::FindRoute {
+                denom_in: "uatom".to_string(),
+                denom_out: "uusdc".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.denoms, vec!["uatom".to_string(), "uosmo".to_string(), "uusdc".to_string()]);
+    assert_eq!(
+        res.route,
+        OsmosisRoute(vec![
+            SwapAmountInRoute {
+                pool_id: pool_atom_osmo,
+                token_out_denom: "uosmo".to_string(),
+            },
+            SwapAmountInRoute {
+                pool_id: pool_osmo_usdc,
+                token_out_denom: "uusdc".to_string(),
+            },
+        ])
+    );
+
+    // the composed route is used for swaps
+    let gamm = Gamm::new(&app);
+    let coin_in_amount = Uint128::from(1000u128);
+    let uatom_price = query_price_from_pool(&gamm, pool_atom_osmo, "uatom");
+    let uosmo_price = query_price_from_pool(&gamm, pool_osmo_usdc, "uosmo");
+    let expected_output = coin_in_amount * uatom_price * uosmo_price;
+
+    let res: EstimateExactInSwapResponse = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::EstimateExactInSwap {
+                coin_in: coin(coin_in_amount.u128(), "uatom"),
+                denom_out: "uusdc".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.amount, expected_output);
+}
+
+#[test]
+fn error_when_no_path_exists() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let signer = init_signer(&app);
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+    let (pool_atom_osmo, pool_osmo_usdc) = create_pools(&app, &signer);
+    set_routes(&wasm, &contract_addr, &signer, pool_atom_osmo, pool_osmo_usdc);
+
+    // only atom -> osmo -> usdc routes are stored, not the other way around
+    let res: RunnerResult<FindRouteResponse<OsmosisRoute>> = wasm.query(
+        &contract_addr,
+        &QueryMsg::FindRoute {
+            denom_in: "uusdc".to_string(),
+            denom_out: "uatom".to_string(),
+        },
+    );
+    assert_err(
+        res.unwrap_err(),
+        ContractError::NoRouteFound {
+            denom_in: "uusdc".to_string(),
+            denom_out: "uatom".to_string(),
+        },
+    );
+}
+
+fn init_signer(app: &OsmosisTestApp) -> SigningAccount {
+    app.init_account(&[
+        coin(1_000_000_000_000, "uatom"),
+        coin(1_000_000_000_000, "uosmo"),
+        coin(1_000_000_000_000, "uusdc"),
+    ])
+    .unwrap()
+}
+
+fn create_pools(app: &OsmosisTestApp, signer: &SigningAccount) -> (u64, u64) {
+    let gamm = Gamm::new(app);
+    let pool_atom_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "uatom"), coin(1_500_000, "uosmo")], signer)
+        .unwrap()
+        .data
+        .pool_id;
+    let pool_osmo_usdc = gamm
+        .create_basic_pool(&[coin(100_000, "uosmo"), coin(1_000_000, "uusdc")], signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(app, signer, pool_atom_osmo, coin(4u128, "uosmo"), "uatom");
+
+    (pool_atom_osmo, pool_osmo_usdc)
+}
+
+fn set_routes(
+    wasm: &Wasm<OsmosisTestApp>,
+    contract_addr: &str,
+    signer: &SigningAccount,
+    pool_atom_osmo: u64,
+    pool_osmo_usdc: u64,
+) {
+    wasm.execute(
+        contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_atom_osmo,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        signer,
+    )
+    .unwrap();
+
+    wasm.execute(
+        contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "uosmo".to_string(),
+            denom_out: "uusdc".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_osmo_usdc,
+                token_out_denom: "uusdc".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        signer,
+    )
+    .unwrap();
+}
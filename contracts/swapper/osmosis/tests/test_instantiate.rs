@@ -1,9 +1,10 @@
-use cosmwasm_std::coin;
+use cosmwasm_std::{coin, Decimal};
 use mars_owner::OwnerResponse;
 use mars_swapper::msgs::{InstantiateMsg, QueryMsg};
+use mars_swapper_base::ContractError;
 use osmosis_test_tube::{Account, Module, OsmosisTestApp, Wasm};
 
-use crate::helpers::{instantiate_contract, wasm_file};
+use crate::helpers::{assert_err, instantiate_contract, wasm_file};
 
 pub mod helpers;
 
@@ -33,6 +34,7 @@ fn raises_on_invalid_owner_addr() {
         code_id,
         &InstantiateMsg {
             owner: owner.to_string(),
+            max_slippage: Decimal::percent(10),
         },
         None,
         Some("swapper-osmosis-contract"),
@@ -44,3 +46,34 @@ fn raises_on_invalid_owner_addr() {
         panic!("Should have thrown an error");
     }
 }
+
+#[test]
+fn raises_on_invalid_max_slippage() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let signer = app.init_account(&[coin(1_000_000_000_000, "uosmo")]).unwrap();
+
+    let wasm_byte_code = std::fs::read(wasm_file()).unwrap();
+    let code_id = wasm.store_code(&wasm_byte_code, None, &signer).unwrap().data.code_id;
+
+    let res_err = wasm
+        .instantiate(
+            code_id,
+            &InstantiateMsg {
+                owner: signer.address(),
+                max_slippage: Decimal::one(),
+            },
+            None,
+            Some("swapper-osmosis-contract"),
+            &[],
+            &signer,
+        )
+        .unwrap_err();
+
+    assert_err(
+        res_err,
+        ContractError::InvalidMaxSlippage {
+            max_slippage: Decimal::one(),
+        },
+    );
+}
@@ -0,0 +1,177 @@
+use cosmwasm_std::{coin, Decimal};
+use mars_owner::OwnerError;
+use mars_swapper::msgs::{ExecuteMsg, MigrateMsg, QueryMsg};
+use mars_swapper_base::ContractError;
+use mars_swapper_osmosis::route::OsmosisRoute;
+use osmosis_test_tube::{Module, OsmosisTestApp, Wasm};
+
+use crate::helpers::{assert_err, instantiate_contract, migrate_contract};
+
+pub mod helpers;
+
+#[test]
+fn max_slippage_set_on_instantiate() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let owner = app.init_account(&[coin(1_000_000_000_000, "uosmo")]).unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &owner);
+
+    let max_slippage: Decimal = wasm.query(&contract_addr, &QueryMsg::MaxSlippage {}).unwrap();
+    assert_eq!(max_slippage, Decimal::percent(10));
+}
+
+#[test]
+fn max_slippage_set_on_migrate() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let owner = app.init_account(&[coin(1_000_000_000_000, "uosmo")]).unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &owner);
+
+    let res_err = migrate_contract(
+        &app,
+        &contract_addr,
+        &MigrateMsg {
+            max_slippage: Decimal::one(),
+        },
+        &owner,
+    )
+    .unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::InvalidMaxSlippage {
+            max_slippage: Decimal::one(),
+        },
+    );
+
+    migrate_contract(
+        &app,
+        &contract_addr,
+        &MigrateMsg {
+            max_slippage: Decimal::percent(3),
+        },
+        &owner,
+    )
+    .unwrap();
+
+    let max_slippage: Decimal = wasm.query(&contract_addr, &QueryMsg::MaxSlippage {}).unwrap();
+    assert_eq!(max_slippage, Decimal::percent(3));
+}
+
+#[test]
+fn only_owner_can_update_max_slippage() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let accs = app.init_accounts(&[coin(1_000_000_000_000, "uosmo")], 2).unwrap();
+    let owner = &accs[0];
+    let bad_guy = &accs[1];
+
+    let contract_addr = instantiate_contract(&wasm, owner);
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::UpdateMaxSlippage {
+                max_slippage: Decimal::percent(50),
+            },
+            &[],
+            bad_guy,
+        )
+        .unwrap_err();
+    assert_err(res_err, OwnerError::NotOwner {});
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::<OsmosisRoute>::UpdateMaxSlippage {
+            max_slippage: Decimal::percent(2),
+        },
+        &[],
+        owner,
+    )
+    .unwrap();
+
+    let max_slippage: Decimal = wasm.query(&contract_addr, &QueryMsg::MaxSlippage {}).unwrap();
+    assert_eq!(max_slippage, Decimal::percent(2));
+}
+
+#[test]
+fn max_slippage_must_be_less_than_one() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let owner = app.init_account(&[coin(1_000_000_000_000, "uosmo")]).unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &owner);
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::UpdateMaxSlippage {
+                max_slippage: Decimal::percent(100),
+            },
+            &[],
+            &owner,
+        )
+        .unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::InvalidMaxSlippage {
+            max_slippage: Decimal::percent(100),
+        },
+    );
+}
+
+#[test]
+fn swap_with_slippage_above_max_fails() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let user =
+        app.init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000, "umars")]).unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &user);
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::SwapExactIn {
+                coin_in: coin(10_000, "umars"),
+                denom_out: "uosmo".to_string(),
+                slippage: Decimal::percent(11),
+                min_receive: None,
+                recipient: None,
+                callback: None,
+                split: None,
+            },
+            &[coin(10_000, "umars")],
+            &user,
+        )
+        .unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::MaxSlippageExceeded {
+            max_slippage: Decimal::percent(10),
+            slippage: Decimal::percent(11),
+        },
+    );
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::SwapExactOut {
+                denom_in: "umars".to_string(),
+                coin_out: coin(2_000, "uosmo"),
+                max_slippage: Decimal::percent(11),
+            },
+            &[coin(10_000, "umars")],
+            &user,
+        )
+        .unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::MaxSlippageExceeded {
+            max_slippage: Decimal::percent(10),
+            slippage: Decimal::percent(11),
+        },
+    );
+}
@@ -0,0 +1,122 @@
+use cosmwasm_std::coin;
+use mars_owner::OwnerError;
+use mars_swapper::msgs::{ExecuteMsg, QueryMsg, RouteResponse};
+use mars_swapper_base::ContractError;
+use mars_swapper_osmosis::route::OsmosisRoute;
+use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
+use osmosis_test_tube::{Gamm, Module, OsmosisTestApp, RunnerResult, Wasm};
+
+use crate::helpers::{assert_err, instantiate_contract};
+
+pub mod helpers;
+
+#[test]
+fn only_owner_can_remove_routes() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let accs = app.init_accounts(&[coin(1_000_000_000_000, "uosmo")], 2).unwrap();
+    let owner = &accs[0];
+    let bad_guy = &accs[1];
+
+    let contract_addr = instantiate_contract(&wasm, owner);
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::RemoveRoute {
+                denom_in: "mars".to_string(),
+                denom_out: "weth".to_string(),
+            },
+            &[],
+            bad_guy,
+        )
+        .unwrap_err();
+
+    assert_err(res_err, OwnerError::NotOwner {});
+}
+
+#[test]
+fn route_must_exist() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app.init_account(&[coin(1_000_000_000_000, "uosmo")]).unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::RemoveRoute {
+                denom_in: "mars".to_string(),
+                denom_out: "weth".to_string(),
+            },
+            &[],
+            &signer,
+        )
+        .unwrap_err();
+
+    assert_err(
+        res_err,
+        ContractError::NoRouteFound {
+            denom_in: "mars".to_string(),
+            denom_out: "weth".to_string(),
+        },
+    );
+}
+
+#[test]
+fn remove_route_success() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_mars_osmo,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::<OsmosisRoute>::RemoveRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    let res: RunnerResult<RouteResponse<OsmosisRoute>> = wasm.query(
+        &contract_addr,
+        &QueryMsg::Route {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+        },
+    );
+    assert_err(res.unwrap_err(), "swapper_osmosis::route::OsmosisRoute not found");
+}
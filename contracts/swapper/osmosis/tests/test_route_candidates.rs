@@ -0,0 +1,266 @@
+use cosmwasm_std::{coin, Decimal};
+use mars_owner::OwnerError;
+use mars_swapper::msgs::{
+    EstimateExactInSwapResponse, ExecuteMsg, QueryMsg, RouteCandidatesResponse,
+};
+use mars_swapper_base::ContractError;
+use mars_swapper_osmosis::route::OsmosisRoute;
+use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
+use osmosis_test_tube::{Gamm, Module, OsmosisTestApp, SigningAccount, Wasm};
+
+use crate::helpers::{assert_err, instantiate_contract, swap_to_create_twap_records};
+
+pub mod helpers;
+
+#[test]
+fn only_owner_can_set_route_candidates() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let accs = app.init_accounts(&[coin(1_000_000_000_000, "uosmo")], 2).unwrap();
+    let owner = &accs[0];
+    let bad_guy = &accs[1];
+
+    let contract_addr = instantiate_contract(&wasm, owner);
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::SetRouteCandidates {
+                denom_in: "uatom".to_string(),
+                denom_out: "uosmo".to_string(),
+                routes: vec![],
+            },
+            &[],
+            bad_guy,
+        )
+        .unwrap_err();
+    assert_err(res_err, OwnerError::NotOwner {});
+}
+
+#[test]
+fn best_candidate_is_used() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uatom"), coin(1_000_000_000_000, "uosmo")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let (route_a, route_b) = create_pools_and_routes(&app, &signer);
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: route_a.clone(),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRouteCandidates {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            routes: vec![route_b.clone()],
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    // pool b gives 5 uosmo per uatom, pool a only 4
+    let res: RouteCandidatesResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::RouteCandidates {
+                coin_in: coin(1_000, "uatom"),
+                denom_out: "uosmo".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.candidates.len(), 2);
+    assert_eq!(res.candidates[0].route, route_a);
+    assert_eq!(res.candidates[1].route, route_b);
+    let amount_a = res.candidates[0].amount_out.unwrap();
+    let amount_b = res.candidates[1].amount_out.unwrap();
+    assert!(amount_b > amount_a);
+
+    let estimate: EstimateExactInSwapResponse = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::EstimateExactInSwap {
+                coin_in: coin(1_000, "uatom"),
+                denom_out: "uosmo".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(estimate.amount, amount_b);
+
+    let res = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::SwapExactIn {
+                coin_in: coin(1_000, "uatom"),
+                denom_out: "uosmo".to_string(),
+                slippage: Decimal::percent(6),
+                min_receive: None,
+                recipient: None,
+                callback: None,
+                split: None,
+            },
+            &[coin(1_000, "uatom")],
+            &signer,
+        )
+        .unwrap();
+
+    let route_index = res
+        .events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == "route_index")
+        .map(|a| a.value.clone())
+        .unwrap();
+    assert_eq!(route_index, "1");
+
+    // clearing the candidates leaves only the route set with `SetRoute`
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::<OsmosisRoute>::SetRouteCandidates {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            routes: vec![],
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    let res: RouteCandidatesResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::RouteCandidates {
+                coin_in: coin(1_000, "uatom"),
+                denom_out: "uosmo".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.candidates.len(), 1);
+    assert_eq!(res.candidates[0].route, route_a);
+}
+
+#[test]
+fn remove_route_clears_candidates() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uatom"), coin(1_000_000_000_000, "uosmo")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let (route_a, route_b) = create_pools_and_routes(&app, &signer);
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: route_a,
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRouteCandidates {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            routes: vec![route_b.clone()],
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    let remove_msg = ExecuteMsg::<OsmosisRoute>::RemoveRoute {
+        denom_in: "uatom".to_string(),
+        denom_out: "uosmo".to_string(),
+    };
+    let candidates_query = QueryMsg::RouteCandidates {
+        coin_in: coin(1_000, "uatom"),
+        denom_out: "uosmo".to_string(),
+    };
+    let no_route_found = ContractError::NoRouteFound {
+        denom_in: "uatom".to_string(),
+        denom_out: "uosmo".to_string(),
+    };
+
+    wasm.execute(&contract_addr, &remove_msg, &[], &signer).unwrap();
+
+    let res_err =
+        wasm.query::<_, RouteCandidatesResponse<OsmosisRoute>>(&contract_addr, &candidates_query);
+    assert_err(res_err.unwrap_err(), &no_route_found);
+
+    // a pair with only candidate routes can be removed too
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRouteCandidates {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            routes: vec![route_b],
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    wasm.execute(&contract_addr, &remove_msg, &[], &signer).unwrap();
+
+    let res_err =
+        wasm.query::<_, RouteCandidatesResponse<OsmosisRoute>>(&contract_addr, &candidates_query);
+    assert_err(res_err.unwrap_err(), &no_route_found);
+
+    // once removed there is nothing left to remove
+    let res_err = wasm.execute(&contract_addr, &remove_msg, &[], &signer).unwrap_err();
+    assert_err(res_err, no_route_found);
+}
+
+fn create_pools_and_routes(
+    app: &OsmosisTestApp,
+    signer: &SigningAccount,
+) -> (OsmosisRoute, OsmosisRoute) {
+    let gamm = Gamm::new(app);
+    let pool_a = gamm
+        .create_basic_pool(&[coin(1_500_000, "uatom"), coin(6_000_000, "uosmo")], signer)
+        .unwrap()
+        .data
+        .pool_id;
+    let pool_b = gamm
+        .create_basic_pool(&[coin(1_000_000, "uatom"), coin(5_000_000, "uosmo")], signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(app, signer, pool_a, coin(10u128, "uatom"), "uosmo");
+
+    let route_a = OsmosisRoute(vec![SwapAmountInRoute {
+        pool_id: pool_a,
+        token_out_denom: "uosmo".to_string(),
+    }]);
+    let route_b = OsmosisRoute(vec![SwapAmountInRoute {
+        pool_id: pool_b,
+        token_out_denom: "uosmo".to_string(),
+    }]);
+
+    (route_a, route_b)
+}
@@ -1,3 +1,4 @@
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{coin, StdError::GenericErr};
 use mars_owner::OwnerError;
 use mars_swapper::msgs::{ExecuteMsg, QueryMsg, RouteResponse};
@@ -10,6 +11,16 @@ use crate::helpers::{assert_err, instantiate_contract};
 
 pub mod helpers;
 
+/// `SetRoute` as sent by clients that don't set `also_reverse`
+#[cw_serde]
+enum SetRouteMsg {
+    SetRoute {
+        denom_in: String,
+        denom_out: String,
+        route: OsmosisRoute,
+    },
+}
+
 #[test]
 fn only_owner_can_set_routes() {
     let app = OsmosisTestApp::new();
@@ -37,6 +48,7 @@ fn only_owner_can_set_routes() {
                         token_out_denom: "weth".to_string(),
                     },
                 ]),
+                also_reverse: false,
             },
             &[],
             bad_guy,
@@ -62,6 +74,7 @@ fn must_pass_at_least_one_step() {
                 denom_in: "mars".to_string(),
                 denom_out: "weth".to_string(),
                 route: OsmosisRoute(vec![]),
+                also_reverse: false,
             },
             &[],
             &signer,
@@ -95,6 +108,7 @@ fn must_be_available_in_osmosis() {
                     pool_id: 1,
                     token_out_denom: "osmo".to_string(),
                 }]),
+                also_reverse: false,
             },
             &[],
             &signer,
@@ -137,6 +151,7 @@ fn step_does_not_contain_input_denom() {
                     pool_id: pool_atom_osmo,
                     token_out_denom: "uosmo".to_string(),
                 }]),
+                also_reverse: false,
             },
             &[],
             &signer,
@@ -179,6 +194,7 @@ fn step_does_not_contain_output_denom() {
                     pool_id: pool_mars_osmo,
                     token_out_denom: "uweth".to_string(),
                 }]),
+                also_reverse: false,
             },
             &[],
             &signer,
@@ -250,6 +266,7 @@ fn steps_do_not_loop() {
                         token_out_denom: "umars".to_string(),
                     },
                 ]),
+                also_reverse: false,
             },
             &[],
             &signer,
@@ -294,6 +311,7 @@ fn step_output_does_not_match() {
                     pool_id: pool_atom_osmo,
                     token_out_denom: "uosmo".to_string(),
                 }]),
+                also_reverse: false,
             },
             &[],
             &signer,
@@ -338,7 +356,7 @@ fn set_route_success() {
 
     wasm.execute(
         &contract_addr,
-        &ExecuteMsg::SetRoute {
+        &SetRouteMsg::SetRoute {
             denom_in: "umars".to_string(),
             denom_out: "uweth".to_string(),
             route: OsmosisRoute(vec![
@@ -370,4 +388,90 @@ fn set_route_success() {
     assert_eq!(res.denom_in, "umars".to_string());
     assert_eq!(res.denom_out, "uweth".to_string());
     assert_eq!(res.route.to_string(), format!("{pool_mars_osmo}:uosmo|{pool_weth_osmo}:uweth"));
+
+    // the reverse route is only set if asked for
+    let res_err = wasm
+        .query::<_, RouteResponse<OsmosisRoute>>(
+            &contract_addr,
+            &QueryMsg::Route {
+                denom_in: "uweth".to_string(),
+                denom_out: "umars".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_err(res_err, "swapper_osmosis::route::OsmosisRoute not found");
+}
+
+#[test]
+fn set_route_with_reverse_success() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[
+            coin(1_000_000_000_000, "uosmo"),
+            coin(1_000_000_000_000, "umars"),
+            coin(1_000_000_000_000, "uweth"),
+        ])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+    let pool_weth_osmo = gamm
+        .create_basic_pool(&[coin(100_000, "uweth"), coin(1_000_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uweth".to_string(),
+            route: OsmosisRoute(vec![
+                SwapAmountInRoute {
+                    pool_id: pool_mars_osmo,
+                    token_out_denom: "uosmo".to_string(),
+                },
+                SwapAmountInRoute {
+                    pool_id: pool_weth_osmo,
+                    token_out_denom: "uweth".to_string(),
+                },
+            ]),
+            also_reverse: true,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    let res: RouteResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::Route {
+                denom_in: "umars".to_string(),
+                denom_out: "uweth".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.route.to_string(), format!("{pool_mars_osmo}:uosmo|{pool_weth_osmo}:uweth"));
+
+    let res: RouteResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::Route {
+                denom_in: "uweth".to_string(),
+                denom_out: "umars".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.denom_in, "uweth".to_string());
+    assert_eq!(res.denom_out, "umars".to_string());
+    assert_eq!(res.route.to_string(), format!("{pool_weth_osmo}:uosmo|{pool_mars_osmo}:umars"));
 }
@@ -0,0 +1,177 @@
+use cosmwasm_std::coin;
+use mars_owner::OwnerError;
+use mars_swapper::msgs::{ExecuteMsg, PairRoute, QueryMsg, RouteResponse, RoutesResponse};
+use mars_swapper_base::ContractError;
+use mars_swapper_osmosis::route::OsmosisRoute;
+use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
+use osmosis_test_tube::{Gamm, Module, OsmosisTestApp, Wasm};
+
+use crate::helpers::{assert_err, instantiate_contract};
+
+pub mod helpers;
+
+#[test]
+fn only_owner_can_set_routes_in_batch() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let accs = app.init_accounts(&[coin(1_000_000_000_000, "uosmo")], 2).unwrap();
+    let owner = &accs[0];
+    let bad_guy = &accs[1];
+
+    let contract_addr = instantiate_contract(&wasm, owner);
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::SetRoutes {
+                routes: vec![PairRoute {
+                    denom_in: "mars".to_string(),
+                    denom_out: "osmo".to_string(),
+                    route: OsmosisRoute(vec![SwapAmountInRoute {
+                        pool_id: 1,
+                        token_out_denom: "osmo".to_string(),
+                    }]),
+                }],
+            },
+            &[],
+            bad_guy,
+        )
+        .unwrap_err();
+
+    assert_err(res_err, OwnerError::NotOwner {});
+}
+
+#[test]
+fn invalid_route_fails_whole_batch() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::SetRoutes {
+                routes: vec![
+                    PairRoute {
+                        denom_in: "umars".to_string(),
+                        denom_out: "uosmo".to_string(),
+                        route: OsmosisRoute(vec![SwapAmountInRoute {
+                            pool_id: pool_mars_osmo,
+                            token_out_denom: "uosmo".to_string(),
+                        }]),
+                    },
+                    PairRoute {
+                        denom_in: "uosmo".to_string(),
+                        denom_out: "umars".to_string(),
+                        route: OsmosisRoute(vec![]),
+                    },
+                ],
+            },
+            &[],
+            &signer,
+        )
+        .unwrap_err();
+
+    assert_err(
+        res_err,
+        ContractError::InvalidRoute {
+            reason: "the route must contain at least one step".to_string(),
+        },
+    );
+
+    let res: RoutesResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::Routes {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.data.is_empty());
+}
+
+#[test]
+fn set_routes_success() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    let res = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::SetRoutes {
+                routes: vec![
+                    PairRoute {
+                        denom_in: "umars".to_string(),
+                        denom_out: "uosmo".to_string(),
+                        route: OsmosisRoute(vec![SwapAmountInRoute {
+                            pool_id: pool_mars_osmo,
+                            token_out_denom: "uosmo".to_string(),
+                        }]),
+                    },
+                    PairRoute {
+                        denom_in: "uosmo".to_string(),
+                        denom_out: "umars".to_string(),
+                        route: OsmosisRoute(vec![SwapAmountInRoute {
+                            pool_id: pool_mars_osmo,
+                            token_out_denom: "umars".to_string(),
+                        }]),
+                    },
+                ],
+            },
+            &[],
+            &signer,
+        )
+        .unwrap();
+
+    let set_route_events = res.events.iter().filter(|e| e.ty == "wasm-set_route").count();
+    assert_eq!(set_route_events, 2);
+
+    let res: RouteResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::Route {
+                denom_in: "umars".to_string(),
+                denom_out: "uosmo".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.route.to_string(), format!("{pool_mars_osmo}:uosmo"));
+
+    let res: RouteResponse<OsmosisRoute> = wasm
+        .query(
+            &contract_addr,
+            &QueryMsg::Route {
+                denom_in: "uosmo".to_string(),
+                denom_out: "umars".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.route.to_string(), format!("{pool_mars_osmo}:umars"));
+}
@@ -0,0 +1,159 @@
+use cosmwasm_std::{coin, Decimal};
+use mars_swapper::msgs::{ExecuteMsg, SwapSplit};
+use mars_swapper_base::ContractError;
+use mars_swapper_osmosis::route::OsmosisRoute;
+use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
+use osmosis_test_tube::{
+    Bank, ExecuteResponse, Gamm, Module, OsmosisTestApp, SigningAccount, Wasm,
+};
+
+use crate::helpers::{
+    assert_err, instantiate_contract, query_balance, swap_to_create_twap_records,
+};
+
+pub mod helpers;
+
+#[test]
+fn weights_must_match_routes() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let signer = init_signer(&app);
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+    set_routes(&app, &wasm, &contract_addr, &signer);
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &swap_msg(SwapSplit::Weights(vec![Decimal::one()])),
+            &[coin(2_000, "uatom")],
+            &signer,
+        )
+        .unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::InvalidSplit {
+            reason: "expected 2 weights, got 1".to_string(),
+        },
+    );
+
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &swap_msg(SwapSplit::Weights(vec![Decimal::zero(), Decimal::zero()])),
+            &[coin(2_000, "uatom")],
+            &signer,
+        )
+        .unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::InvalidSplit {
+            reason: "weights must not all be zero".to_string(),
+        },
+    );
+}
+
+#[test]
+fn split_by_weights() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let bank = Bank::new(&app);
+    let signer = init_signer(&app);
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+    set_routes(&app, &wasm, &contract_addr, &signer);
+
+    let res = wasm
+        .execute(
+            &contract_addr,
+            &swap_msg(SwapSplit::Weights(vec![Decimal::percent(25), Decimal::percent(75)])),
+            &[coin(2_000, "uatom")],
+            &signer,
+        )
+        .unwrap();
+
+    assert_eq!(attribute(&res, "route_index"), "0,1");
+    assert_eq!(attribute(&res, "leg_amounts_in"), "500,1500");
+
+    // Assert no tokens in contract left over
+    let osmo_balance = query_balance(&bank, &contract_addr, "uosmo");
+    let atom_balance = query_balance(&bank, &contract_addr, "uatom");
+    assert_eq!(osmo_balance, 0);
+    assert_eq!(atom_balance, 0);
+}
+
+fn swap_msg(split: SwapSplit) -> ExecuteMsg<OsmosisRoute> {
+    ExecuteMsg::SwapExactIn {
+        coin_in: coin(2_000, "uatom"),
+        denom_out: "uosmo".to_string(),
+        slippage: Decimal::percent(6),
+        min_receive: None,
+        recipient: None,
+        callback: None,
+        split: Some(split),
+    }
+}
+
+fn attribute<T>(res: &ExecuteResponse<T>, key: &str) -> String {
+    res.events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == key)
+        .map(|a| a.value.clone())
+        .unwrap()
+}
+
+fn init_signer(app: &OsmosisTestApp) -> SigningAccount {
+    app.init_account(&[coin(1_000_000_000_000, "uatom"), coin(1_000_000_000_000, "uosmo")]).unwrap()
+}
+
+fn set_routes(
+    app: &OsmosisTestApp,
+    wasm: &Wasm<OsmosisTestApp>,
+    contract_addr: &str,
+    signer: &SigningAccount,
+) {
+    let gamm = Gamm::new(app);
+    let pool_a = gamm
+        .create_basic_pool(&[coin(1_500_000, "uatom"), coin(6_000_000, "uosmo")], signer)
+        .unwrap()
+        .data
+        .pool_id;
+    let pool_b = gamm
+        .create_basic_pool(&[coin(1_000_000, "uatom"), coin(5_000_000, "uosmo")], signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(app, signer, pool_a, coin(10u128, "uatom"), "uosmo");
+
+    wasm.execute(
+        contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_a,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        signer,
+    )
+    .unwrap();
+    wasm.execute(
+        contract_addr,
+        &ExecuteMsg::SetRouteCandidates {
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+            routes: vec![OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_b,
+                token_out_denom: "uosmo".to_string(),
+            }])],
+        },
+        &[],
+        signer,
+    )
+    .unwrap();
+}
@@ -1,8 +1,10 @@
-use cosmwasm_std::{coin, Addr, Coin, Decimal};
+use cosmwasm_std::{coin, to_binary, Addr, Coin, Decimal, Uint128};
 use mars_swapper::ExecuteMsg;
 use mars_swapper_base::ContractError;
 use mars_swapper_osmosis::route::OsmosisRoute;
-use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
+use osmosis_std::types::{
+    cosmos::bank::v1beta1::MsgSend, osmosis::poolmanager::v1beta1::SwapAmountInRoute,
+};
 use osmosis_test_tube::{Account, Bank, FeeSetting, Gamm, Module, OsmosisTestApp, Wasm};
 
 use crate::helpers::{
@@ -29,6 +31,10 @@ fn transfer_callback_only_internal() {
                 recipient: Addr::unchecked(bad_guy.address()),
                 denom_in: "mars".to_string(),
                 denom_out: "osmo".to_string(),
+                min_receive: None,
+                denom_in_balance_before: Uint128::zero(),
+                denom_out_balance_before: Uint128::zero(),
+                callback: None,
             },
             &[],
             bad_guy,
@@ -44,6 +50,67 @@ fn transfer_callback_only_internal() {
     );
 }
 
+#[test]
+fn swapper_cannot_be_recipient() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let bank = Bank::new(&app);
+
+    let accs = app
+        .init_accounts(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")], 2)
+        .unwrap();
+    let owner = &accs[0];
+    let bad_guy = &accs[1];
+
+    let contract_addr = instantiate_contract(&wasm, owner);
+
+    // someone sends funds to the contract directly
+    bank.send(
+        MsgSend {
+            from_address: owner.address(),
+            to_address: contract_addr.clone(),
+            amount: vec![coin(10_000, "uosmo").into()],
+        },
+        owner,
+    )
+    .unwrap();
+
+    // a callback executed by the swapper on itself would pass the internal-only check
+    let callback = to_binary(&ExecuteMsg::<OsmosisRoute>::TransferResult {
+        recipient: Addr::unchecked(bad_guy.address()),
+        denom_in: "umars".to_string(),
+        denom_out: "uosmo".to_string(),
+        min_receive: None,
+        denom_in_balance_before: Uint128::zero(),
+        denom_out_balance_before: Uint128::zero(),
+        callback: None,
+    })
+    .unwrap();
+
+    for callback in [Some(callback), None] {
+        let res_err = wasm
+            .execute(
+                &contract_addr,
+                &ExecuteMsg::<OsmosisRoute>::SwapExactIn {
+                    coin_in: coin(1_000, "umars"),
+                    denom_out: "uosmo".to_string(),
+                    slippage: Decimal::percent(6),
+                    min_receive: None,
+                    recipient: Some(contract_addr.clone()),
+                    callback,
+                    split: None,
+                },
+                &[coin(1_000, "umars")],
+                bad_guy,
+            )
+            .unwrap_err();
+        assert_err(res_err, ContractError::SwapperAsRecipient {});
+    }
+
+    let osmo_balance = query_balance(&bank, &contract_addr, "uosmo");
+    assert_eq!(osmo_balance, 10_000);
+}
+
 #[test]
 fn swap_exact_in_slippage_too_high() {
     let app = OsmosisTestApp::new();
@@ -76,6 +143,7 @@ fn swap_exact_in_slippage_too_high() {
             denom_in: "umars".to_string(),
             denom_out: "uosmo".to_string(),
             route,
+            also_reverse: false,
         },
         &[],
         &signer,
@@ -90,6 +158,10 @@ fn swap_exact_in_slippage_too_high() {
                 coin_in: coin(1_000_000, "umars"),
                 denom_out: "uosmo".to_string(),
                 slippage: Decimal::percent(5),
+                min_receive: None,
+                recipient: None,
+                callback: None,
+                split: None,
             },
             &[coin(1_000_000, "umars")],
             &whale,
@@ -141,6 +213,7 @@ fn swap_exact_in_success() {
                 pool_id: pool_mars_osmo,
                 token_out_denom: "uosmo".to_string(),
             }]),
+            also_reverse: false,
         },
         &[],
         &signer,
@@ -159,6 +232,10 @@ fn swap_exact_in_success() {
             coin_in: coin(10_000, "umars"),
             denom_out: "uosmo".to_string(),
             slippage: Decimal::percent(6),
+            min_receive: None,
+            recipient: None,
+            callback: None,
+            split: None,
         },
         &[coin(10_000, "umars")],
         &user,
@@ -177,3 +254,466 @@ fn swap_exact_in_success() {
     assert_eq!(osmo_balance, 0);
     assert_eq!(mars_balance, 0);
 }
+
+#[test]
+fn swap_exact_out_requires_funds() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let user = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &user);
+
+    let swap_msg = ExecuteMsg::<OsmosisRoute>::SwapExactOut {
+        denom_in: "umars".to_string(),
+        coin_out: coin(2_000, "uosmo"),
+        max_slippage: Decimal::percent(6),
+    };
+
+    let res_err = wasm.execute(&contract_addr, &swap_msg, &[], &user).unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::NoFundsSent {
+            denom: "umars".to_string(),
+        },
+    );
+
+    // other denoms sent along would be stuck in the contract
+    let res_err = wasm
+        .execute(&contract_addr, &swap_msg, &[coin(10_000, "umars"), coin(1_000, "uosmo")], &user)
+        .unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::UnexpectedDenoms {
+            denom: "umars".to_string(),
+        },
+    );
+}
+
+#[test]
+fn swap_exact_out_success() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let tx_fee = 1_000_000u128;
+    let user_osmo_starting_amount = 10_000_000u128;
+    let user = app
+        .init_account(&[coin(10_000, "umars"), coin(user_osmo_starting_amount, "uosmo")])
+        .unwrap()
+        .with_fee_setting(FeeSetting::Custom {
+            amount: Coin::new(tx_fee, "uosmo"),
+            gas_limit: tx_fee as u64,
+        });
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(&app, &signer, pool_mars_osmo, coin(10u128, "umars"), "uosmo");
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_mars_osmo,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::<OsmosisRoute>::SwapExactOut {
+            denom_in: "umars".to_string(),
+            coin_out: coin(2_000, "uosmo"),
+            max_slippage: Decimal::percent(6),
+        },
+        &[coin(10_000, "umars")],
+        &user,
+    )
+    .unwrap();
+
+    // Assert user receives the exact output and gets the unused input back
+    let bank = Bank::new(&app);
+    let osmo_balance = query_balance(&bank, &user.address(), "uosmo");
+    let mars_balance = query_balance(&bank, &user.address(), "umars");
+    assert_eq!(osmo_balance, 2_000 + user_osmo_starting_amount - tx_fee);
+    assert!(mars_balance > 0 && mars_balance < 10_000);
+
+    // Assert no tokens in contract left over
+    let osmo_balance = query_balance(&bank, &contract_addr, "uosmo");
+    let mars_balance = query_balance(&bank, &contract_addr, "umars");
+    assert_eq!(osmo_balance, 0);
+    assert_eq!(mars_balance, 0);
+}
+
+#[test]
+fn swap_exact_out_spends_at_most_funds_sent() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let bank = Bank::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(&app, &signer, pool_mars_osmo, coin(10u128, "umars"), "uosmo");
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_mars_osmo,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    // someone sends funds to the contract directly
+    bank.send(
+        MsgSend {
+            from_address: signer.address(),
+            to_address: contract_addr.clone(),
+            amount: vec![coin(10_000, "umars").into()],
+        },
+        &signer,
+    )
+    .unwrap();
+
+    // 2_000 uosmo costs ~8_000 umars, more than what is sent
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::SwapExactOut {
+                denom_in: "umars".to_string(),
+                coin_out: coin(2_000, "uosmo"),
+                max_slippage: Decimal::percent(6),
+            },
+            &[coin(5_000, "umars")],
+            &signer,
+        )
+        .unwrap_err();
+
+    // the swap itself is rejected by Osmosis, rather than drawing on the contract's funds
+    assert_err(res_err, "calculated amount is larger than max amount");
+
+    let mars_balance = query_balance(&bank, &contract_addr, "umars");
+    assert_eq!(mars_balance, 10_000);
+}
+
+#[test]
+fn swap_exact_in_with_min_receive() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+    let whale = app.init_account(&[coin(2_000_000, "umars"), coin(1_000_000, "uosmo")]).unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(&app, &signer, pool_mars_osmo, coin(10u128, "umars"), "uosmo");
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_mars_osmo,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    // min receive above what the pool can return fails, regardless of slippage
+    let res_err = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::SwapExactIn {
+                coin_in: coin(1_000_000, "umars"),
+                denom_out: "uosmo".to_string(),
+                slippage: Decimal::percent(5),
+                min_receive: Some(Uint128::new(250_000)),
+                recipient: None,
+                callback: None,
+                split: None,
+            },
+            &[coin(1_000_000, "umars")],
+            &whale,
+        )
+        .unwrap_err();
+    assert_err(
+        res_err,
+        "uosmo token is lesser than min amount: calculated amount is lesser than min amount",
+    );
+
+    // the same trade fails with 5% slippage (see `swap_exact_in_slippage_too_high`), but succeeds
+    // when the caller accepts a lower minimum
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::<OsmosisRoute>::SwapExactIn {
+            coin_in: coin(1_000_000, "umars"),
+            denom_out: "uosmo".to_string(),
+            slippage: Decimal::percent(5),
+            min_receive: Some(Uint128::new(200_000)),
+            recipient: None,
+            callback: None,
+            split: None,
+        },
+        &[coin(1_000_000, "umars")],
+        &whale,
+    )
+    .unwrap();
+
+    let bank = Bank::new(&app);
+    let osmo_balance = query_balance(&bank, &contract_addr, "uosmo");
+    let mars_balance = query_balance(&bank, &contract_addr, "umars");
+    assert_eq!(osmo_balance, 0);
+    assert_eq!(mars_balance, 0);
+}
+
+#[test]
+fn swap_exact_in_only_transfers_swap_output() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let bank = Bank::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(&app, &signer, pool_mars_osmo, coin(10u128, "umars"), "uosmo");
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_mars_osmo,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    // someone sends funds to the contract directly
+    bank.send(
+        MsgSend {
+            from_address: signer.address(),
+            to_address: contract_addr.clone(),
+            amount: vec![coin(1_000, "uosmo").into(), coin(1_000, "umars").into()],
+        },
+        &signer,
+    )
+    .unwrap();
+
+    let res = wasm
+        .execute(
+            &contract_addr,
+            &ExecuteMsg::<OsmosisRoute>::SwapExactIn {
+                coin_in: coin(10_000, "umars"),
+                denom_out: "uosmo".to_string(),
+                slippage: Decimal::percent(6),
+                min_receive: None,
+                recipient: None,
+                callback: None,
+                split: None,
+            },
+            &[coin(10_000, "umars")],
+            &signer,
+        )
+        .unwrap();
+
+    let amount_out = res
+        .events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == "amount_out")
+        .map(|a| a.value.clone())
+        .unwrap();
+    assert_eq!(amount_out, "2470");
+
+    // funds that weren't part of the swap stay in the contract
+    let osmo_balance = query_balance(&bank, &contract_addr, "uosmo");
+    let mars_balance = query_balance(&bank, &contract_addr, "umars");
+    assert_eq!(osmo_balance, 1_000);
+    assert_eq!(mars_balance, 1_000);
+}
+
+#[test]
+fn swap_exact_in_requires_exact_funds() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let bank = Bank::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    // someone sends funds to the contract directly
+    bank.send(
+        MsgSend {
+            from_address: signer.address(),
+            to_address: contract_addr.clone(),
+            amount: vec![coin(10_000, "umars").into()],
+        },
+        &signer,
+    )
+    .unwrap();
+
+    let swap_msg = ExecuteMsg::<OsmosisRoute>::SwapExactIn {
+        coin_in: coin(10_000, "umars"),
+        denom_out: "uosmo".to_string(),
+        slippage: Decimal::percent(6),
+        min_receive: None,
+        recipient: None,
+        callback: None,
+        split: None,
+    };
+
+    let res_err = wasm.execute(&contract_addr, &swap_msg, &[], &signer).unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::NoFundsSent {
+            denom: "umars".to_string(),
+        },
+    );
+
+    let res_err =
+        wasm.execute(&contract_addr, &swap_msg, &[coin(1_000, "umars")], &signer).unwrap_err();
+    assert_err(
+        res_err,
+        ContractError::UnexpectedFunds {
+            expected: coin(10_000, "umars"),
+        },
+    );
+
+    // funds that weren't sent for the swap stay in the contract
+    let mars_balance = query_balance(&bank, &contract_addr, "umars");
+    assert_eq!(mars_balance, 10_000);
+}
+
+#[test]
+fn swap_exact_in_to_recipient() {
+    let app = OsmosisTestApp::new();
+    let wasm = Wasm::new(&app);
+    let bank = Bank::new(&app);
+
+    let signer = app
+        .init_account(&[coin(1_000_000_000_000, "uosmo"), coin(1_000_000_000_000, "umars")])
+        .unwrap();
+    let recipient = app.init_account(&[]).unwrap();
+
+    let contract_addr = instantiate_contract(&wasm, &signer);
+
+    let gamm = Gamm::new(&app);
+    let pool_mars_osmo = gamm
+        .create_basic_pool(&[coin(6_000_000, "umars"), coin(1_500_000, "uosmo")], &signer)
+        .unwrap()
+        .data
+        .pool_id;
+
+    swap_to_create_twap_records(&app, &signer, pool_mars_osmo, coin(10u128, "umars"), "uosmo");
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::SetRoute {
+            denom_in: "umars".to_string(),
+            denom_out: "uosmo".to_string(),
+            route: OsmosisRoute(vec![SwapAmountInRoute {
+                pool_id: pool_mars_osmo,
+                token_out_denom: "uosmo".to_string(),
+            }]),
+            also_reverse: false,
+        },
+        &[],
+        &signer,
+    )
+    .unwrap();
+
+    wasm.execute(
+        &contract_addr,
+        &ExecuteMsg::<OsmosisRoute>::SwapExactIn {
+            coin_in: coin(10_000, "umars"),
+            denom_out: "uosmo".to_string(),
+            slippage: Decimal::percent(6),
+            min_receive: None,
+            recipient: Some(recipient.address()),
+            callback: None,
+            split: None,
+        },
+        &[coin(10_000, "umars")],
+        &signer,
+    )
+    .unwrap();
+
+    // Assert recipient receives the new tokens
+    let osmo_balance = query_balance(&bank, &recipient.address(), "uosmo");
+    assert_eq!(osmo_balance, 2470);
+
+    // Assert no tokens in contract left over
+    let osmo_balance = query_balance(&bank, &contract_addr, "uosmo");
+    let mars_balance = query_balance(&bank, &contract_addr, "umars");
+    assert_eq!(osmo_balance, 0);
+    assert_eq!(mars_balance, 0);
+}
@@ -1,5 +1,8 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_binary, Addr, Api, Coin, CosmosMsg, Decimal, Empty, StdResult, WasmMsg};
+use cosmwasm_std::{
+    to_binary, Addr, Api, Coin, CosmosMsg, Decimal, Empty, StdResult, Uint128, WasmMsg,
+};
+use serde::Serialize;
 
 use crate::msgs::ExecuteMsg;
 
@@ -45,8 +48,101 @@ impl Swapper {
                 coin_in: coin_in.clone(),
                 denom_out: denom_out.to_string(),
                 slippage,
+                min_receive: None,
+                recipient: None,
+                callback: None,
+                split: None,
             })?,
             funds: vec![coin_in.clone()],
         }))
     }
+
+    /// Generate message for performing a swapper that sends the output to `recipient`
+    pub fn swap_exact_in_to_recipient_msg(
+        &self,
+        coin_in: &Coin,
+        denom_out: &str,
+        slippage: Decimal,
+        recipient: &Addr,
+    ) -> StdResult<CosmosMsg> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: self.address().to_string(),
+            msg: to_binary(&ExecuteMsg::<Empty>::SwapExactIn {
+                coin_in: coin_in.clone(),
+                denom_out: denom_out.to_string(),
+                slippage,
+                min_receive: None,
+                recipient: Some(recipient.to_string()),
+                callback: None,
+                split: None,
+            })?,
+            funds: vec![coin_in.clone()],
+        }))
+    }
+
+    /// Generate message for performing a swapper that executes `callback` on `recipient` with the
+    /// output attached. Fails if the swap has no output.
+    pub fn swap_exact_in_with_callback_msg<T: Serialize>(
+        &self,
+        coin_in: &Coin,
+        denom_out: &str,
+        slippage: Decimal,
+        recipient: &Addr,
+        callback: &T,
+    ) -> StdResult<CosmosMsg> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: self.address().to_string(),
+            msg: to_binary(&ExecuteMsg::<Empty>::SwapExactIn {
+                coin_in: coin_in.clone(),
+                denom_out: denom_out.to_string(),
+                slippage,
+                min_receive: None,
+                recipient: Some(recipient.to_string()),
+                callback: Some(to_binary(callback)?),
+                split: None,
+            })?,
+            funds: vec![coin_in.clone()],
+        }))
+    }
+
+    /// Generate message for performing a swapper that must return at least `min_receive`
+    pub fn swap_exact_in_with_min_receive_msg(
+        &self,
+        coin_in: &Coin,
+        denom_out: &str,
+        slippage: Decimal,
+        min_receive: Uint128,
+    ) -> StdResult<CosmosMsg> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: self.address().to_string(),
+            msg: to_binary(&ExecuteMsg::<Empty>::SwapExactIn {
+                coin_in: coin_in.clone(),
+                denom_out: denom_out.to_string(),
+                slippage,
+                min_receive: Some(min_receive),
+                recipient: None,
+                callback: None,
+                split: None,
+            })?,
+            funds: vec![coin_in.clone()],
+        }))
+    }
+
+    /// Generate message for performing an exact-out swap. Unused input is refunded.
+    pub fn swap_exact_out_msg(
+        &self,
+        coin_in_max: &Coin,
+        coin_out: &Coin,
+        max_slippage: Decimal,
+    ) -> StdResult<CosmosMsg> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: self.address().to_string(),
+            msg: to_binary(&ExecuteMsg::<Empty>::SwapExactOut {
+                denom_in: coin_in_max.denom.clone(),
+                coin_out: coin_out.clone(),
+                max_slippage,
+            })?,
+            funds: vec![coin_in_max.clone()],
+        }))
+    }
 }
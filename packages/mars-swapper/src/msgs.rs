@@ -1,17 +1,29 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Uint128};
 use mars_owner::OwnerUpdate;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     /// The contract's owner, who can update config
     pub owner: String,
+    /// Maximum slippage allowed for swaps, must be less than 1
+    pub max_slippage: Decimal,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Maximum slippage allowed for swaps, stored on contracts deployed before it was introduced
+    pub max_slippage: Decimal,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg<Route> {
     /// Manges owner role state
     UpdateOwner(OwnerUpdate),
+    /// Update the maximum slippage allowed for swaps. Only callable by the owner.
+    UpdateMaxSlippage {
+        max_slippage: Decimal,
+    },
     /// Configure the route for swapping an asset
     ///
     /// This is chain-specific, and can include parameters such as slippage tolerance and the routes
@@ -20,33 +32,115 @@ pub enum ExecuteMsg<Route> {
         denom_in: String,
         denom_out: String,
         route: Route,
+        /// Also set the route swapping denom_out to denom_in, derived by reversing this one.
+        /// Defaults to false if omitted.
+        #[serde(default)]
+        also_reverse: bool,
+    },
+    /// Configure routes for several pairs at once, overwriting existing ones
+    SetRoutes {
+        routes: Vec<PairRoute<Route>>,
+    },
+    /// Remove the route for swapping an asset, along with its candidate routes
+    RemoveRoute {
+        denom_in: String,
+        denom_out: String,
+    },
+    /// Configure additional candidate routes for swapping an asset. Exact-in swaps use whichever
+    /// of these and the route set with `SetRoute` gives the best estimated output.
+    ///
+    /// Replaces previously set candidates, an empty list removes them.
+    SetRouteCandidates {
+        denom_in: String,
+        denom_out: String,
+        routes: Vec<Route>,
     },
     /// Perform a swapper with an exact-in amount. Requires slippage allowance %.
     SwapExactIn {
         coin_in: Coin,
         denom_out: String,
         slippage: Decimal,
+        /// Minimum amount of denom_out to receive. If provided, it is used as the swap's minimum
+        /// output instead of the one derived from `slippage`.
+        min_receive: Option<Uint128>,
+        /// Address receiving the swap's output, defaults to the sender. Can't be the swapper itself.
+        recipient: Option<String>,
+        /// Message to execute on the recipient with the swap's output attached as funds, instead
+        /// of sending the output with a bank transfer. The swap must have a non-zero output.
+        ///
+        /// Anyone can have the swapper execute a callback, so contracts must not trust messages
+        /// for being sent by the swapper.
+        callback: Option<Binary>,
+        /// Split coin_in across the pair's routes (see `RouteCandidates`) instead of swapping it
+        /// all through the best one. `min_receive` then applies to the combined output.
+        split: Option<SwapSplit>,
+    },
+    /// Perform a swap for an exact output amount. Requires slippage allowance %.
+    ///
+    /// Only the input denom must be sent along, it caps how much the swap can spend. Whatever isn't
+    /// spent is refunded.
+    SwapExactOut {
+        denom_in: String,
+        coin_out: Coin,
+        max_slippage: Decimal,
     },
     /// Send swapper results back to swapper. Also refunds extra if sent more than needed. Internal use only.
     TransferResult {
         recipient: Addr,
         denom_in: String,
         denom_out: String,
+        /// Minimum amount of denom_out that must have been received
+        min_receive: Option<Uint128>,
+        /// Contract's balance of denom_in before the swap, excluding the funds sent for it
+        denom_in_balance_before: Uint128,
+        /// Contract's balance of denom_out before the swap
+        denom_out_balance_before: Uint128,
+        /// Message to execute on the recipient with the output attached
+        callback: Option<Binary>,
     },
 }
 
+#[cw_serde]
+pub struct PairRoute<Route> {
+    pub denom_in: String,
+    pub denom_out: String,
+    pub route: Route,
+}
+
+#[cw_serde]
+pub enum SwapSplit {
+    /// Split by the given weights, one per route in the order returned by `RouteCandidates`
+    Weights(Vec<Decimal>),
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
     /// Query contract owner config
     #[returns(mars_owner::OwnerResponse)]
     Owner {},
+    /// Maximum slippage allowed for swaps
+    #[returns(Decimal)]
+    MaxSlippage {},
     /// Get route for swapping an input denom into an output denom
     #[returns(RouteResponse<cosmwasm_std::Empty>)]
     Route {
         denom_in: String,
         denom_out: String,
     },
+    /// Get the route used for swapping an input denom into an output denom. If there is no route
+    /// stored for the pair, it is composed from stored routes through intermediate denoms.
+    #[returns(FindRouteResponse<cosmwasm_std::Empty>)]
+    FindRoute {
+        denom_in: String,
+        denom_out: String,
+    },
+    /// List all routes considered for an exact-in swap, with their estimated output
+    #[returns(RouteCandidatesResponse<cosmwasm_std::Empty>)]
+    RouteCandidates {
+        coin_in: Coin,
+        denom_out: String,
+    },
     /// Enumerate all swapper routes
     #[returns(RoutesResponse<cosmwasm_std::Empty>)]
     Routes {
@@ -60,6 +154,13 @@ pub enum QueryMsg {
         coin_in: Coin,
         denom_out: String,
     },
+    /// Return current spot amount of denom_in needed to receive exactly coin_out
+    /// Warning: Do not use this as an oracle price feed. Use Mars-Oracle for pricing.
+    #[returns(EstimateExactOutSwapResponse)]
+    EstimateExactOutSwap {
+        denom_in: String,
+        coin_out: Coin,
+    },
 }
 
 #[cw_serde]
@@ -69,9 +170,41 @@ pub struct RouteResponse<Route> {
     pub route: Route,
 }
 
-pub type RoutesResponse<Route> = Vec<RouteResponse<Route>>;
+#[cw_serde]
+pub struct FindRouteResponse<Route> {
+    /// Denoms the swap goes through, starting with denom_in and ending with denom_out
+    pub denoms: Vec<String>,
+    pub route: Route,
+}
+
+#[cw_serde]
+pub struct RouteCandidate<Route> {
+    pub route: Route,
+    /// Estimated output of the swap, `null` if it could not be estimated
+    pub amount_out: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct RouteCandidatesResponse<Route> {
+    pub candidates: Vec<RouteCandidate<Route>>,
+}
+
+#[cw_serde]
+pub struct RoutesResponse<Route> {
+    pub data: Vec<RouteResponse<Route>>,
+    /// Value to pass as `start_after` to get the next page, `null` if this is the last page
+    pub next_start_after: Option<(String, String)>,
+    /// Number of routes across all pages
+    pub total_count: u64,
+}
 
 #[cw_serde]
 pub struct EstimateExactInSwapResponse {
     pub amount: Uint128,
 }
+
+#[cw_serde]
+pub struct EstimateExactOutSwapResponse {
+    /// Amount of the input denom needed
+    pub amount: Uint128,
+}
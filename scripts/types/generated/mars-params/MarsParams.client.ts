@@ -13,50 +13,92 @@ import {
   ExecuteMsg,
   OwnerUpdate,
   AssetParamsUpdate,
-  HlsAssetTypeForString,
   Uint128,
+  HlsAssetTypeForString,
+  PriceSource,
   VaultConfigUpdate,
+  AssetCategoryUpdate,
   EmergencyUpdate,
   CmEmergencyUpdate,
   RedBankEmergencyUpdate,
   AssetParamsBaseForString,
   CmSettingsForString,
   HlsParamsBaseForString,
+  IsolationSettings,
   LiquidationBonus,
   RedBankSettings,
+  InterestRateModel,
   VaultConfigBaseForString,
   Coin,
+  VaultLockup,
+  AssetCategory,
   QueryMsg,
+  AssetParamsFilter,
+  VaultConfigsFilter,
+  MigrateMsg,
+  PaginationResponseForAssetCategory,
   HlsAssetTypeForAddr,
   Addr,
-  ArrayOfAssetParamsBaseForAddr,
+  PaginationResponseForAssetParamsBaseForAddr,
   AssetParamsBaseForAddr,
   CmSettingsForAddr,
   HlsParamsBaseForAddr,
-  ArrayOfVaultConfigBaseForAddr,
+  PaginationResponseForVaultConfigBaseForAddr,
   VaultConfigBaseForAddr,
+  MapOfNullableAssetParamsBaseForAddr,
+  IsolationCheckResponse,
   OwnerResponse,
+  MapOfNullableVaultConfigBaseForAddr,
 } from './MarsParams.types'
 export interface MarsParamsReadOnlyInterface {
   contractAddress: string
   owner: () => Promise<OwnerResponse>
   assetParams: ({ denom }: { denom: string }) => Promise<AssetParamsBaseForAddr>
   allAssetParams: ({
+    filter,
     limit,
     startAfter,
   }: {
+    filter?: AssetParamsFilter
     limit?: number
     startAfter?: string
-  }) => Promise<ArrayOfAssetParamsBaseForAddr>
+  }) => Promise<PaginationResponseForAssetParamsBaseForAddr>
+  assetParamsBatch: ({
+    denoms,
+  }: {
+    denoms: string[]
+  }) => Promise<MapOfNullableAssetParamsBaseForAddr>
   vaultConfig: ({ address }: { address: string }) => Promise<VaultConfigBaseForAddr>
   allVaultConfigs: ({
+    filter,
     limit,
     startAfter,
   }: {
+    filter?: VaultConfigsFilter
     limit?: number
     startAfter?: string
-  }) => Promise<ArrayOfVaultConfigBaseForAddr>
+  }) => Promise<PaginationResponseForVaultConfigBaseForAddr>
+  vaultConfigsBatch: ({
+    addresses,
+  }: {
+    addresses: string[]
+  }) => Promise<MapOfNullableVaultConfigBaseForAddr>
+  assetCategory: ({ name }: { name: string }) => Promise<AssetCategory>
+  allAssetCategories: ({
+    limit,
+    startAfter,
+  }: {
+    limit?: number
+    startAfter?: string
+  }) => Promise<PaginationResponseForAssetCategory>
   targetHealthFactor: () => Promise<Decimal>
+  isolationCheck: ({
+    collateralDenom,
+    debtDenom,
+  }: {
+    collateralDenom: string
+    debtDenom: string
+  }) => Promise<IsolationCheckResponse>
 }
 export class MarsParamsQueryClient implements MarsParamsReadOnlyInterface {
   client: CosmWasmClient
@@ -68,9 +110,14 @@ export class MarsParamsQueryClient implements MarsParamsReadOnlyInterface {
     this.owner = this.owner.bind(this)
     this.assetParams = this.assetParams.bind(this)
     this.allAssetParams = this.allAssetParams.bind(this)
+    this.assetParamsBatch = this.assetParamsBatch.bind(this)
     this.vaultConfig = this.vaultConfig.bind(this)
     this.allVaultConfigs = this.allVaultConfigs.bind(this)
+    this.vaultConfigsBatch = this.vaultConfigsBatch.bind(this)
+    this.assetCategory = this.assetCategory.bind(this)
+    this.allAssetCategories = this.allAssetCategories.bind(this)
     this.targetHealthFactor = this.targetHealthFactor.bind(this)
+    this.isolationCheck = this.isolationCheck.bind(this)
   }
 
   owner = async (): Promise<OwnerResponse> => {
@@ -86,19 +133,33 @@ export class MarsParamsQueryClient implements MarsParamsReadOnlyInterface {
     })
   }
   allAssetParams = async ({
+    filter,
     limit,
     startAfter,
   }: {
+    filter?: AssetParamsFilter
     limit?: number
     startAfter?: string
-  }): Promise<ArrayOfAssetParamsBaseForAddr> => {
+  }): Promise<PaginationResponseForAssetParamsBaseForAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       all_asset_params: {
+        filter,
         limit,
         start_after: startAfter,
       },
     })
   }
+  assetParamsBatch = async ({
+    denoms,
+  }: {
+    denoms: string[]
+  }): Promise<MapOfNullableAssetParamsBaseForAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      asset_params_batch: {
+        denoms,
+      },
+    })
+  }
   vaultConfig = async ({ address }: { address: string }): Promise<VaultConfigBaseForAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       vault_config: {
@@ -107,14 +168,49 @@ export class MarsParamsQueryClient implements MarsParamsReadOnlyInterface {
     })
   }
   allVaultConfigs = async ({
+    filter,
     limit,
     startAfter,
   }: {
+    filter?: VaultConfigsFilter
     limit?: number
     startAfter?: string
-  }): Promise<ArrayOfVaultConfigBaseForAddr> => {
+  }): Promise<PaginationResponseForVaultConfigBaseForAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       all_vault_configs: {
+        filter,
+        limit,
+        start_after: startAfter,
+      },
+    })
+  }
+  vaultConfigsBatch = async ({
+    addresses,
+  }: {
+    addresses: string[]
+  }): Promise<MapOfNullableVaultConfigBaseForAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      vault_configs_batch: {
+        addresses,
+      },
+    })
+  }
+  assetCategory = async ({ name }: { name: string }): Promise<AssetCategory> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      asset_category: {
+        name,
+      },
+    })
+  }
+  allAssetCategories = async ({
+    limit,
+    startAfter,
+  }: {
+    limit?: number
+    startAfter?: string
+  }): Promise<PaginationResponseForAssetCategory> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      all_asset_categories: {
         limit,
         start_after: startAfter,
       },
@@ -125,6 +221,20 @@ export class MarsParamsQueryClient implements MarsParamsReadOnlyInterface {
       target_health_factor: {},
     })
   }
+  isolationCheck = async ({
+    collateralDenom,
+    debtDenom,
+  }: {
+    collateralDenom: string
+    debtDenom: string
+  }): Promise<IsolationCheckResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      isolation_check: {
+        collateral_denom: collateralDenom,
+        debt_denom: debtDenom,
+      },
+    })
+  }
 }
 export interface MarsParamsInterface extends MarsParamsReadOnlyInterface {
   contractAddress: string
@@ -152,6 +262,12 @@ export interface MarsParamsInterface extends MarsParamsReadOnlyInterface {
     memo?: string,
     _funds?: Coin[],
   ) => Promise<ExecuteResult>
+  updateAssetCategory: (
+    assetCategoryUpdate: AssetCategoryUpdate,
+    fee?: number | StdFee | 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ) => Promise<ExecuteResult>
   emergencyUpdate: (
     emergencyUpdate: EmergencyUpdate,
     fee?: number | StdFee | 'auto',
@@ -173,6 +289,7 @@ export class MarsParamsClient extends MarsParamsQueryClient implements MarsParam
     this.updateTargetHealthFactor = this.updateTargetHealthFactor.bind(this)
     this.updateAssetParams = this.updateAssetParams.bind(this)
     this.updateVaultConfig = this.updateVaultConfig.bind(this)
+    this.updateAssetCategory = this.updateAssetCategory.bind(this)
     this.emergencyUpdate = this.emergencyUpdate.bind(this)
   }
 
@@ -243,6 +360,23 @@ export class MarsParamsClient extends MarsParamsQueryClient implements MarsParam
       _funds,
     )
   }
+  updateAssetCategory = async (
+    assetCategoryUpdate: AssetCategoryUpdate,
+    fee: number | StdFee | 'auto' = 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        update_asset_category: assetCategoryUpdate,
+      },
+      fee,
+      memo,
+      _funds,
+    )
+  }
   emergencyUpdate = async (
     emergencyUpdate: EmergencyUpdate,
     fee: number | StdFee | 'auto' = 'auto',
@@ -14,29 +14,42 @@ import {
   ExecuteMsg,
   OwnerUpdate,
   AssetParamsUpdate,
-  HlsAssetTypeForString,
   Uint128,
+  HlsAssetTypeForString,
+  PriceSource,
   VaultConfigUpdate,
+  AssetCategoryUpdate,
   EmergencyUpdate,
   CmEmergencyUpdate,
   RedBankEmergencyUpdate,
   AssetParamsBaseForString,
   CmSettingsForString,
   HlsParamsBaseForString,
+  IsolationSettings,
   LiquidationBonus,
   RedBankSettings,
+  InterestRateModel,
   VaultConfigBaseForString,
   Coin,
+  VaultLockup,
+  AssetCategory,
   QueryMsg,
+  AssetParamsFilter,
+  VaultConfigsFilter,
+  MigrateMsg,
+  PaginationResponseForAssetCategory,
   HlsAssetTypeForAddr,
   Addr,
-  ArrayOfAssetParamsBaseForAddr,
+  PaginationResponseForAssetParamsBaseForAddr,
   AssetParamsBaseForAddr,
   CmSettingsForAddr,
   HlsParamsBaseForAddr,
-  ArrayOfVaultConfigBaseForAddr,
+  PaginationResponseForVaultConfigBaseForAddr,
   VaultConfigBaseForAddr,
+  MapOfNullableAssetParamsBaseForAddr,
+  IsolationCheckResponse,
   OwnerResponse,
+  MapOfNullableVaultConfigBaseForAddr,
 } from './MarsParams.types'
 import { MarsParamsQueryClient, MarsParamsClient } from './MarsParams.client'
 export const marsParamsQueryKeys = {
@@ -55,16 +68,36 @@ export const marsParamsQueryKeys = {
     [
       { ...marsParamsQueryKeys.address(contractAddress)[0], method: 'all_asset_params', args },
     ] as const,
+  assetParamsBatch: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      { ...marsParamsQueryKeys.address(contractAddress)[0], method: 'asset_params_batch', args },
+    ] as const,
   vaultConfig: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
     [{ ...marsParamsQueryKeys.address(contractAddress)[0], method: 'vault_config', args }] as const,
   allVaultConfigs: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
     [
       { ...marsParamsQueryKeys.address(contractAddress)[0], method: 'all_vault_configs', args },
     ] as const,
+  vaultConfigsBatch: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      { ...marsParamsQueryKeys.address(contractAddress)[0], method: 'vault_configs_batch', args },
+    ] as const,
+  assetCategory: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      { ...marsParamsQueryKeys.address(contractAddress)[0], method: 'asset_category', args },
+    ] as const,
+  allAssetCategories: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      { ...marsParamsQueryKeys.address(contractAddress)[0], method: 'all_asset_categories', args },
+    ] as const,
   targetHealthFactor: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
     [
       { ...marsParamsQueryKeys.address(contractAddress)[0], method: 'target_health_factor', args },
     ] as const,
+  isolationCheck: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      { ...marsParamsQueryKeys.address(contractAddress)[0], method: 'isolation_check', args },
+    ] as const,
 }
 export interface MarsParamsReactQuery<TResponse, TData = TResponse> {
   client: MarsParamsQueryClient | undefined
@@ -75,6 +108,30 @@ export interface MarsParamsReactQuery<TResponse, TData = TResponse> {
     initialData?: undefined
   }
 }
+export interface MarsParamsIsolationCheckQuery<TData>
+  extends MarsParamsReactQuery<IsolationCheckResponse, TData> {
+  args: {
+    collateralDenom: string
+    debtDenom: string
+  }
+}
+export function useMarsParamsIsolationCheckQuery<TData = IsolationCheckResponse>({
+  client,
+  args,
+  options,
+}: MarsParamsIsolationCheckQuery<TData>) {
+  return useQuery<IsolationCheckResponse, Error, TData>(
+    marsParamsQueryKeys.isolationCheck(client?.contractAddress, args),
+    () =>
+      client
+        ? client.isolationCheck({
+            collateralDenom: args.collateralDenom,
+            debtDenom: args.debtDenom,
+          })
+        : Promise.reject(new Error('Invalid client')),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
 export interface MarsParamsTargetHealthFactorQuery<TData>
   extends MarsParamsReactQuery<Decimal, TData> {}
 export function useMarsParamsTargetHealthFactorQuery<TData = Decimal>({
@@ -87,23 +144,91 @@ export function useMarsParamsTargetHealthFactorQuery<TData = Decimal>({
     { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
   )
 }
-export interface MarsParamsAllVaultConfigsQuery<TData>
-  extends MarsParamsReactQuery<ArrayOfVaultConfigBaseForAddr, TData> {
+export interface MarsParamsAllAssetCategoriesQuery<TData>
+  extends MarsParamsReactQuery<PaginationResponseForAssetCategory, TData> {
   args: {
     limit?: number
     startAfter?: string
   }
 }
-export function useMarsParamsAllVaultConfigsQuery<TData = ArrayOfVaultConfigBaseForAddr>({
+export function useMarsParamsAllAssetCategoriesQuery<TData = PaginationResponseForAssetCategory>({
+  client,
+  args,
+  options,
+}: MarsParamsAllAssetCategoriesQuery<TData>) {
+  return useQuery<PaginationResponseForAssetCategory, Error, TData>(
+    marsParamsQueryKeys.allAssetCategories(client?.contractAddress, args),
+    () =>
+      client
+        ? client.allAssetCategories({
+            limit: args.limit,
+            startAfter: args.startAfter,
+          })
+        : Promise.reject(new Error('Invalid client')),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
+export interface MarsParamsAssetCategoryQuery<TData>
+  extends MarsParamsReactQuery<AssetCategory, TData> {
+  args: {
+    name: string
+  }
+}
+export function useMarsParamsAssetCategoryQuery<TData = AssetCategory>({
+  client,
+  args,
+  options,
+}: MarsParamsAssetCategoryQuery<TData>) {
+  return useQuery<AssetCategory, Error, TData>(
+    marsParamsQueryKeys.assetCategory(client?.contractAddress, args),
+    () =>
+      client
+        ? client.assetCategory({
+            name: args.name,
+          })
+        : Promise.reject(new Error('Invalid client')),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
+export interface MarsParamsVaultConfigsBatchQuery<TData>
+  extends MarsParamsReactQuery<MapOfNullableVaultConfigBaseForAddr, TData> {
+  args: {
+    addresses: string[]
+  }
+}
+export function useMarsParamsVaultConfigsBatchQuery<TData = MapOfNullableVaultConfigBaseForAddr>({
   client,
   args,
   options,
-}: MarsParamsAllVaultConfigsQuery<TData>) {
-  return useQuery<ArrayOfVaultConfigBaseForAddr, Error, TData>(
+}: MarsParamsVaultConfigsBatchQuery<TData>) {
+  return useQuery<MapOfNullableVaultConfigBaseForAddr, Error, TData>(
+    marsParamsQueryKeys.vaultConfigsBatch(client?.contractAddress, args),
+    () =>
+      client
+        ? client.vaultConfigsBatch({
+            addresses: args.addresses,
+          })
+        : Promise.reject(new Error('Invalid client')),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
+export interface MarsParamsAllVaultConfigsQuery<TData>
+  extends MarsParamsReactQuery<PaginationResponseForVaultConfigBaseForAddr, TData> {
+  args: {
+    filter?: VaultConfigsFilter
+    limit?: number
+    startAfter?: string
+  }
+}
+export function useMarsParamsAllVaultConfigsQuery<
+  TData = PaginationResponseForVaultConfigBaseForAddr,
+>({ client, args, options }: MarsParamsAllVaultConfigsQuery<TData>) {
+  return useQuery<PaginationResponseForVaultConfigBaseForAddr, Error, TData>(
     marsParamsQueryKeys.allVaultConfigs(client?.contractAddress, args),
     () =>
       client
         ? client.allVaultConfigs({
+            filter: args.filter,
             limit: args.limit,
             startAfter: args.startAfter,
           })
@@ -133,23 +258,45 @@ export function useMarsParamsVaultConfigQuery<TData = VaultConfigBaseForAddr>({
     { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
   )
 }
-export interface MarsParamsAllAssetParamsQuery<TData>
-  extends MarsParamsReactQuery<ArrayOfAssetParamsBaseForAddr, TData> {
+export interface MarsParamsAssetParamsBatchQuery<TData>
+  extends MarsParamsReactQuery<MapOfNullableAssetParamsBaseForAddr, TData> {
   args: {
-    limit?: number
-    startAfter?: string
+    denoms: string[]
   }
 }
-export function useMarsParamsAllAssetParamsQuery<TData = ArrayOfAssetParamsBaseForAddr>({
+export function useMarsParamsAssetParamsBatchQuery<TData = MapOfNullableAssetParamsBaseForAddr>({
   client,
   args,
   options,
-}: MarsParamsAllAssetParamsQuery<TData>) {
-  return useQuery<ArrayOfAssetParamsBaseForAddr, Error, TData>(
+}: MarsParamsAssetParamsBatchQuery<TData>) {
+  return useQuery<MapOfNullableAssetParamsBaseForAddr, Error, TData>(
+    marsParamsQueryKeys.assetParamsBatch(client?.contractAddress, args),
+    () =>
+      client
+        ? client.assetParamsBatch({
+            denoms: args.denoms,
+          })
+        : Promise.reject(new Error('Invalid client')),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
+export interface MarsParamsAllAssetParamsQuery<TData>
+  extends MarsParamsReactQuery<PaginationResponseForAssetParamsBaseForAddr, TData> {
+  args: {
+    filter?: AssetParamsFilter
+    limit?: number
+    startAfter?: string
+  }
+}
+export function useMarsParamsAllAssetParamsQuery<
+  TData = PaginationResponseForAssetParamsBaseForAddr,
+>({ client, args, options }: MarsParamsAllAssetParamsQuery<TData>) {
+  return useQuery<PaginationResponseForAssetParamsBaseForAddr, Error, TData>(
     marsParamsQueryKeys.allAssetParams(client?.contractAddress, args),
     () =>
       client
         ? client.allAssetParams({
+            filter: args.filter,
             limit: args.limit,
             startAfter: args.startAfter,
           })
@@ -211,6 +358,27 @@ export function useMarsParamsEmergencyUpdateMutation(
     options,
   )
 }
+export interface MarsParamsUpdateAssetCategoryMutation {
+  client: MarsParamsClient
+  msg: AssetCategoryUpdate
+  args?: {
+    fee?: number | StdFee | 'auto'
+    memo?: string
+    funds?: Coin[]
+  }
+}
+export function useMarsParamsUpdateAssetCategoryMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, MarsParamsUpdateAssetCategoryMutation>,
+    'mutationFn'
+  >,
+) {
+  return useMutation<ExecuteResult, Error, MarsParamsUpdateAssetCategoryMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.updateAssetCategory(msg, fee, memo, funds),
+    options,
+  )
+}
 export interface MarsParamsUpdateVaultConfigMutation {
   client: MarsParamsClient
   msg: VaultConfigUpdate
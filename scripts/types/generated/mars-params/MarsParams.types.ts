@@ -23,6 +23,9 @@ export type ExecuteMsg =
   | {
       update_vault_config: VaultConfigUpdate
     }
+  | {
+      update_asset_category: AssetCategoryUpdate
+    }
   | {
       emergency_update: EmergencyUpdate
     }
@@ -46,6 +49,7 @@ export type AssetParamsUpdate = {
     params: AssetParamsBaseForString
   }
 }
+export type Uint128 = string
 export type HlsAssetTypeForString =
   | {
       coin: {
@@ -57,12 +61,56 @@ export type HlsAssetTypeForString =
         addr: string
       }
     }
-export type Uint128 = string
+  | {
+      category: {
+        name: string
+      }
+    }
+export type PriceSource =
+  | {
+      fixed: {
+        price: Decimal
+      }
+    }
+  | {
+      spot: {
+        pool_id: number
+      }
+    }
+  | {
+      arithmetic_twap: {
+        pool_id: number
+        window_size: number
+      }
+    }
+  | {
+      geometric_twap: {
+        pool_id: number
+        window_size: number
+      }
+    }
+  | {
+      staked_derivative: {
+        max_staleness: number
+        transitive_denom: string
+      }
+    }
 export type VaultConfigUpdate = {
   add_or_update: {
     config: VaultConfigBaseForString
   }
 }
+export type AssetCategoryUpdate =
+  | {
+      add_or_update: {
+        category: AssetCategory
+      }
+    }
+  | {
+      remove: {
+        name: string
+      }
+    }
 export type EmergencyUpdate =
   | {
       credit_manager: CmEmergencyUpdate
@@ -80,20 +128,30 @@ export type CmEmergencyUpdate =
   | {
       disallow_coin: string
     }
-export type RedBankEmergencyUpdate = {
-  disable_borrowing: string
-}
+export type RedBankEmergencyUpdate =
+  | {
+      disable_borrowing: string
+    }
+  | {
+      set_zero_borrow_cap: string
+    }
 export interface AssetParamsBaseForString {
+  close_factor?: Decimal | null
   credit_manager: CmSettingsForString
   denom: string
+  isolation?: IsolationSettings | null
   liquidation_bonus: LiquidationBonus
   liquidation_threshold: Decimal
   max_loan_to_value: Decimal
+  min_liquidation_value?: Uint128 | null
+  price_source?: PriceSource | null
   protocol_liquidation_fee: Decimal
   red_bank: RedBankSettings
 }
 export interface CmSettingsForString {
+  exposure_cap?: Uint128 & string
   hls?: HlsParamsBaseForString | null
+  max_concentration?: Decimal | null
   whitelisted: boolean
 }
 export interface HlsParamsBaseForString {
@@ -101,6 +159,10 @@ export interface HlsParamsBaseForString {
   liquidation_threshold: Decimal
   max_loan_to_value: Decimal
 }
+export interface IsolationSettings {
+  allowed_debt_denoms: string[]
+  debt_ceiling: Uint128
+}
 export interface LiquidationBonus {
   max_lb: Decimal
   min_lb: Decimal
@@ -108,16 +170,28 @@ export interface LiquidationBonus {
   starting_lb: Decimal
 }
 export interface RedBankSettings {
+  borrow_cap?: Uint128 & string
   borrow_enabled: boolean
   deposit_cap: Uint128
   deposit_enabled: boolean
+  interest_rate_model?: InterestRateModel | null
+  reserve_factor?: Decimal & string
+}
+export interface InterestRateModel {
+  base: Decimal
+  optimal_utilization_rate: Decimal
+  slope_1: Decimal
+  slope_2: Decimal
 }
 export interface VaultConfigBaseForString {
   addr: string
   deposit_cap: Coin
   hls?: HlsParamsBaseForString | null
+  liquidation_bonus?: LiquidationBonus | null
   liquidation_threshold: Decimal
+  lockup?: VaultLockup | null
   max_loan_to_value: Decimal
+  protocol_liquidation_fee?: Decimal | null
   whitelisted: boolean
 }
 export interface Coin {
@@ -125,6 +199,17 @@ export interface Coin {
   denom: string
   [k: string]: unknown
 }
+export interface VaultLockup {
+  duration: number
+  force_unlock_supported: boolean
+}
+export interface AssetCategory {
+  denoms: string[]
+  liquidation_bonus?: LiquidationBonus | null
+  liquidation_threshold: Decimal
+  max_loan_to_value: Decimal
+  name: string
+}
 export type QueryMsg =
   | {
       owner: {}
@@ -136,10 +221,16 @@ export type QueryMsg =
     }
   | {
       all_asset_params: {
+        filter?: AssetParamsFilter | null
         limit?: number | null
         start_after?: string | null
       }
     }
+  | {
+      asset_params_batch: {
+        denoms: string[]
+      }
+    }
   | {
       vault_config: {
         address: string
@@ -147,6 +238,23 @@ export type QueryMsg =
     }
   | {
       all_vault_configs: {
+        filter?: VaultConfigsFilter | null
+        limit?: number | null
+        start_after?: string | null
+      }
+    }
+  | {
+      vault_configs_batch: {
+        addresses: string[]
+      }
+    }
+  | {
+      asset_category: {
+        name: string
+      }
+    }
+  | {
+      all_asset_categories: {
         limit?: number | null
         start_after?: string | null
       }
@@ -154,6 +262,22 @@ export type QueryMsg =
   | {
       target_health_factor: {}
     }
+  | {
+      isolation_check: {
+        collateral_denom: string
+        debt_denom: string
+      }
+    }
+export type AssetParamsFilter = 'cm_whitelisted' | 'borrow_enabled' | 'hls_enabled'
+export type VaultConfigsFilter = 'whitelisted' | 'non_zero_deposit_cap'
+export interface MigrateMsg {
+  default_reserve_factor: Decimal
+}
+export interface PaginationResponseForAssetCategory {
+  data: AssetCategory[]
+  next_start_after?: string | null
+  total_count: number
+}
 export type HlsAssetTypeForAddr =
   | {
       coin: {
@@ -165,19 +289,34 @@ export type HlsAssetTypeForAddr =
         addr: Addr
       }
     }
+  | {
+      category: {
+        name: string
+      }
+    }
 export type Addr = string
-export type ArrayOfAssetParamsBaseForAddr = AssetParamsBaseForAddr[]
+export interface PaginationResponseForAssetParamsBaseForAddr {
+  data: AssetParamsBaseForAddr[]
+  next_start_after?: string | null
+  total_count: number
+}
 export interface AssetParamsBaseForAddr {
+  close_factor?: Decimal | null
   credit_manager: CmSettingsForAddr
   denom: string
+  isolation?: IsolationSettings | null
   liquidation_bonus: LiquidationBonus
   liquidation_threshold: Decimal
   max_loan_to_value: Decimal
+  min_liquidation_value?: Uint128 | null
+  price_source?: PriceSource | null
   protocol_liquidation_fee: Decimal
   red_bank: RedBankSettings
 }
 export interface CmSettingsForAddr {
+  exposure_cap?: Uint128 & string
   hls?: HlsParamsBaseForAddr | null
+  max_concentration?: Decimal | null
   whitelisted: boolean
 }
 export interface HlsParamsBaseForAddr {
@@ -185,15 +324,29 @@ export interface HlsParamsBaseForAddr {
   liquidation_threshold: Decimal
   max_loan_to_value: Decimal
 }
-export type ArrayOfVaultConfigBaseForAddr = VaultConfigBaseForAddr[]
+export interface PaginationResponseForVaultConfigBaseForAddr {
+  data: VaultConfigBaseForAddr[]
+  next_start_after?: string | null
+  total_count: number
+}
 export interface VaultConfigBaseForAddr {
   addr: Addr
   deposit_cap: Coin
   hls?: HlsParamsBaseForAddr | null
+  liquidation_bonus?: LiquidationBonus | null
   liquidation_threshold: Decimal
+  lockup?: VaultLockup | null
   max_loan_to_value: Decimal
+  protocol_liquidation_fee?: Decimal | null
   whitelisted: boolean
 }
+export interface MapOfNullableAssetParamsBaseForAddr {
+  [k: string]: AssetParamsBaseForAddr | null
+}
+export interface IsolationCheckResponse {
+  allowed: boolean
+  debt_ceiling?: Uint128 | null
+}
 export interface OwnerResponse {
   abolished: boolean
   emergency_owner?: string | null
@@ -201,3 +354,6 @@ export interface OwnerResponse {
   owner?: string | null
   proposed?: string | null
 }
+export interface MapOfNullableVaultConfigBaseForAddr {
+  [k: string]: VaultConfigBaseForAddr | null
+}
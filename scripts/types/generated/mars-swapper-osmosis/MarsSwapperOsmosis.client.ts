@@ -8,25 +8,34 @@
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from '@cosmjs/cosmwasm-stargate'
 import { StdFee } from '@cosmjs/amino'
 import {
+  Decimal,
   InstantiateMsg,
   ExecuteMsg,
   OwnerUpdate,
   OsmosisRoute,
+  Binary,
   Uint128,
-  Decimal,
+  SwapSplit,
   Addr,
   SwapAmountInRoute,
+  PairRouteForOsmosisRoute,
   Coin,
   QueryMsg,
+  MigrateMsg,
   EstimateExactInSwapResponse,
+  EstimateExactOutSwapResponse,
+  FindRouteResponseForEmpty,
+  Empty,
   OwnerResponse,
   RouteResponseForEmpty,
-  Empty,
-  ArrayOfRouteResponseForEmpty,
+  RouteCandidatesResponseForEmpty,
+  RouteCandidateForEmpty,
+  RoutesResponseForEmpty,
 } from './MarsSwapperOsmosis.types'
 export interface MarsSwapperOsmosisReadOnlyInterface {
   contractAddress: string
   owner: () => Promise<OwnerResponse>
+  maxSlippage: () => Promise<Decimal>
   route: ({
     denomIn,
     denomOut,
@@ -34,13 +43,27 @@ export interface MarsSwapperOsmosisReadOnlyInterface {
     denomIn: string
     denomOut: string
   }) => Promise<RouteResponseForEmpty>
+  findRoute: ({
+    denomIn,
+    denomOut,
+  }: {
+    denomIn: string
+    denomOut: string
+  }) => Promise<FindRouteResponseForEmpty>
+  routeCandidates: ({
+    coinIn,
+    denomOut,
+  }: {
+    coinIn: Coin
+    denomOut: string
+  }) => Promise<RouteCandidatesResponseForEmpty>
   routes: ({
     limit,
     startAfter,
   }: {
     limit?: number
     startAfter?: string[][]
-  }) => Promise<ArrayOfRouteResponseForEmpty>
+  }) => Promise<RoutesResponseForEmpty>
   estimateExactInSwap: ({
     coinIn,
     denomOut,
@@ -48,6 +71,13 @@ export interface MarsSwapperOsmosisReadOnlyInterface {
     coinIn: Coin
     denomOut: string
   }) => Promise<EstimateExactInSwapResponse>
+  estimateExactOutSwap: ({
+    coinOut,
+    denomIn,
+  }: {
+    coinOut: Coin
+    denomIn: string
+  }) => Promise<EstimateExactOutSwapResponse>
 }
 export class MarsSwapperOsmosisQueryClient implements MarsSwapperOsmosisReadOnlyInterface {
   client: CosmWasmClient
@@ -57,9 +87,13 @@ export class MarsSwapperOsmosisQueryClient implements MarsSwapperOsmosisReadOnly
     this.client = client
     this.contractAddress = contractAddress
     this.owner = this.owner.bind(this)
+    this.maxSlippage = this.maxSlippage.bind(this)
     this.route = this.route.bind(this)
+    this.findRoute = this.findRoute.bind(this)
+    this.routeCandidates = this.routeCandidates.bind(this)
     this.routes = this.routes.bind(this)
     this.estimateExactInSwap = this.estimateExactInSwap.bind(this)
+    this.estimateExactOutSwap = this.estimateExactOutSwap.bind(this)
   }
 
   owner = async (): Promise<OwnerResponse> => {
@@ -67,6 +101,11 @@ export class MarsSwapperOsmosisQueryClient implements MarsSwapperOsmosisReadOnly
       owner: {},
     })
   }
+  maxSlippage = async (): Promise<Decimal> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      max_slippage: {},
+    })
+  }
   route = async ({
     denomIn,
     denomOut,
@@ -81,13 +120,41 @@ export class MarsSwapperOsmosisQueryClient implements MarsSwapperOsmosisReadOnly
       },
     })
   }
+  findRoute = async ({
+    denomIn,
+    denomOut,
+  }: {
+    denomIn: string
+    denomOut: string
+  }): Promise<FindRouteResponseForEmpty> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      find_route: {
+        denom_in: denomIn,
+        denom_out: denomOut,
+      },
+    })
+  }
+  routeCandidates = async ({
+    coinIn,
+    denomOut,
+  }: {
+    coinIn: Coin
+    denomOut: string
+  }): Promise<RouteCandidatesResponseForEmpty> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      route_candidates: {
+        coin_in: coinIn,
+        denom_out: denomOut,
+      },
+    })
+  }
   routes = async ({
     limit,
     startAfter,
   }: {
     limit?: number
     startAfter?: string[][]
-  }): Promise<ArrayOfRouteResponseForEmpty> => {
+  }): Promise<RoutesResponseForEmpty> => {
     return this.client.queryContractSmart(this.contractAddress, {
       routes: {
         limit,
@@ -109,6 +176,20 @@ export class MarsSwapperOsmosisQueryClient implements MarsSwapperOsmosisReadOnly
       },
     })
   }
+  estimateExactOutSwap = async ({
+    coinOut,
+    denomIn,
+  }: {
+    coinOut: Coin
+    denomIn: string
+  }): Promise<EstimateExactOutSwapResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      estimate_exact_out_swap: {
+        coin_out: coinOut,
+        denom_in: denomIn,
+      },
+    })
+  }
 }
 export interface MarsSwapperOsmosisInterface extends MarsSwapperOsmosisReadOnlyInterface {
   contractAddress: string
@@ -119,12 +200,24 @@ export interface MarsSwapperOsmosisInterface extends MarsSwapperOsmosisReadOnlyI
     memo?: string,
     _funds?: Coin[],
   ) => Promise<ExecuteResult>
+  updateMaxSlippage: (
+    {
+      maxSlippage,
+    }: {
+      maxSlippage: Decimal
+    },
+    fee?: number | StdFee | 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ) => Promise<ExecuteResult>
   setRoute: (
     {
+      alsoReverse,
       denomIn,
       denomOut,
       route,
     }: {
+      alsoReverse?: boolean
       denomIn: string
       denomOut: string
       route: OsmosisRoute
@@ -133,15 +226,73 @@ export interface MarsSwapperOsmosisInterface extends MarsSwapperOsmosisReadOnlyI
     memo?: string,
     _funds?: Coin[],
   ) => Promise<ExecuteResult>
+  setRoutes: (
+    {
+      routes,
+    }: {
+      routes: PairRouteForOsmosisRoute[]
+    },
+    fee?: number | StdFee | 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ) => Promise<ExecuteResult>
+  removeRoute: (
+    {
+      denomIn,
+      denomOut,
+    }: {
+      denomIn: string
+      denomOut: string
+    },
+    fee?: number | StdFee | 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ) => Promise<ExecuteResult>
+  setRouteCandidates: (
+    {
+      denomIn,
+      denomOut,
+      routes,
+    }: {
+      denomIn: string
+      denomOut: string
+      routes: OsmosisRoute[]
+    },
+    fee?: number | StdFee | 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ) => Promise<ExecuteResult>
   swapExactIn: (
     {
+      callback,
       coinIn,
       denomOut,
+      minReceive,
+      recipient,
       slippage,
+      split,
     }: {
+      callback?: Binary
       coinIn: Coin
       denomOut: string
+      minReceive?: Uint128
+      recipient?: string
       slippage: Decimal
+      split?: SwapSplit
+    },
+    fee?: number | StdFee | 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ) => Promise<ExecuteResult>
+  swapExactOut: (
+    {
+      coinOut,
+      denomIn,
+      maxSlippage,
+    }: {
+      coinOut: Coin
+      denomIn: string
+      maxSlippage: Decimal
     },
     fee?: number | StdFee | 'auto',
     memo?: string,
@@ -149,12 +300,20 @@ export interface MarsSwapperOsmosisInterface extends MarsSwapperOsmosisReadOnlyI
   ) => Promise<ExecuteResult>
   transferResult: (
     {
+      callback,
       denomIn,
+      denomInBalanceBefore,
       denomOut,
+      denomOutBalanceBefore,
+      minReceive,
       recipient,
     }: {
+      callback?: Binary
       denomIn: string
+      denomInBalanceBefore: Uint128
       denomOut: string
+      denomOutBalanceBefore: Uint128
+      minReceive?: Uint128
       recipient: Addr
     },
     fee?: number | StdFee | 'auto',
@@ -176,8 +335,13 @@ export class MarsSwapperOsmosisClient
     this.sender = sender
     this.contractAddress = contractAddress
     this.updateOwner = this.updateOwner.bind(this)
+    this.updateMaxSlippage = this.updateMaxSlippage.bind(this)
     this.setRoute = this.setRoute.bind(this)
+    this.setRoutes = this.setRoutes.bind(this)
+    this.removeRoute = this.removeRoute.bind(this)
+    this.setRouteCandidates = this.setRouteCandidates.bind(this)
     this.swapExactIn = this.swapExactIn.bind(this)
+    this.swapExactOut = this.swapExactOut.bind(this)
     this.transferResult = this.transferResult.bind(this)
   }
 
@@ -198,12 +362,37 @@ export class MarsSwapperOsmosisClient
       _funds,
     )
   }
+  updateMaxSlippage = async (
+    {
+      maxSlippage,
+    }: {
+      maxSlippage: Decimal
+    },
+    fee: number | StdFee | 'auto' = 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        update_max_slippage: {
+          max_slippage: maxSlippage,
+        },
+      },
+      fee,
+      memo,
+      _funds,
+    )
+  }
   setRoute = async (
     {
+      alsoReverse,
       denomIn,
       denomOut,
       route,
     }: {
+      alsoReverse?: boolean
       denomIn: string
       denomOut: string
       route: OsmosisRoute
@@ -217,6 +406,7 @@ export class MarsSwapperOsmosisClient
       this.contractAddress,
       {
         set_route: {
+          also_reverse: alsoReverse,
           denom_in: denomIn,
           denom_out: denomOut,
           route,
@@ -227,15 +417,101 @@ export class MarsSwapperOsmosisClient
       _funds,
     )
   }
+  setRoutes = async (
+    {
+      routes,
+    }: {
+      routes: PairRouteForOsmosisRoute[]
+    },
+    fee: number | StdFee | 'auto' = 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        set_routes: {
+          routes,
+        },
+      },
+      fee,
+      memo,
+      _funds,
+    )
+  }
+  removeRoute = async (
+    {
+      denomIn,
+      denomOut,
+    }: {
+      denomIn: string
+      denomOut: string
+    },
+    fee: number | StdFee | 'auto' = 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        remove_route: {
+          denom_in: denomIn,
+          denom_out: denomOut,
+        },
+      },
+      fee,
+      memo,
+      _funds,
+    )
+  }
+  setRouteCandidates = async (
+    {
+      denomIn,
+      denomOut,
+      routes,
+    }: {
+      denomIn: string
+      denomOut: string
+      routes: OsmosisRoute[]
+    },
+    fee: number | StdFee | 'auto' = 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        set_route_candidates: {
+          denom_in: denomIn,
+          denom_out: denomOut,
+          routes,
+        },
+      },
+      fee,
+      memo,
+      _funds,
+    )
+  }
   swapExactIn = async (
     {
+      callback,
       coinIn,
       denomOut,
+      minReceive,
+      recipient,
       slippage,
+      split,
     }: {
+      callback?: Binary
       coinIn: Coin
       denomOut: string
+      minReceive?: Uint128
+      recipient?: string
       slippage: Decimal
+      split?: SwapSplit
     },
     fee: number | StdFee | 'auto' = 'auto',
     memo?: string,
@@ -246,9 +522,42 @@ export class MarsSwapperOsmosisClient
       this.contractAddress,
       {
         swap_exact_in: {
+          callback,
           coin_in: coinIn,
           denom_out: denomOut,
+          min_receive: minReceive,
+          recipient,
           slippage,
+          split,
+        },
+      },
+      fee,
+      memo,
+      _funds,
+    )
+  }
+  swapExactOut = async (
+    {
+      coinOut,
+      denomIn,
+      maxSlippage,
+    }: {
+      coinOut: Coin
+      denomIn: string
+      maxSlippage: Decimal
+    },
+    fee: number | StdFee | 'auto' = 'auto',
+    memo?: string,
+    _funds?: Coin[],
+  ): Promise<ExecuteResult> => {
+    return await this.client.execute(
+      this.sender,
+      this.contractAddress,
+      {
+        swap_exact_out: {
+          coin_out: coinOut,
+          denom_in: denomIn,
+          max_slippage: maxSlippage,
         },
       },
       fee,
@@ -258,12 +567,20 @@ export class MarsSwapperOsmosisClient
   }
   transferResult = async (
     {
+      callback,
       denomIn,
+      denomInBalanceBefore,
       denomOut,
+      denomOutBalanceBefore,
+      minReceive,
       recipient,
     }: {
+      callback?: Binary
       denomIn: string
+      denomInBalanceBefore: Uint128
       denomOut: string
+      denomOutBalanceBefore: Uint128
+      minReceive?: Uint128
       recipient: Addr
     },
     fee: number | StdFee | 'auto' = 'auto',
@@ -275,8 +592,12 @@ export class MarsSwapperOsmosisClient
       this.contractAddress,
       {
         transfer_result: {
+          callback,
           denom_in: denomIn,
+          denom_in_balance_before: denomInBalanceBefore,
           denom_out: denomOut,
+          denom_out_balance_before: denomOutBalanceBefore,
+          min_receive: minReceive,
           recipient,
         },
       },
@@ -9,21 +9,29 @@ import { UseQueryOptions, useQuery, useMutation, UseMutationOptions } from '@tan
 import { ExecuteResult } from '@cosmjs/cosmwasm-stargate'
 import { StdFee } from '@cosmjs/amino'
 import {
+  Decimal,
   InstantiateMsg,
   ExecuteMsg,
   OwnerUpdate,
   OsmosisRoute,
+  Binary,
   Uint128,
-  Decimal,
+  SwapSplit,
   Addr,
   SwapAmountInRoute,
+  PairRouteForOsmosisRoute,
   Coin,
   QueryMsg,
+  MigrateMsg,
   EstimateExactInSwapResponse,
+  EstimateExactOutSwapResponse,
+  FindRouteResponseForEmpty,
+  Empty,
   OwnerResponse,
   RouteResponseForEmpty,
-  Empty,
-  ArrayOfRouteResponseForEmpty,
+  RouteCandidatesResponseForEmpty,
+  RouteCandidateForEmpty,
+  RoutesResponseForEmpty,
 } from './MarsSwapperOsmosis.types'
 import {
   MarsSwapperOsmosisQueryClient,
@@ -41,10 +49,26 @@ export const marsSwapperOsmosisQueryKeys = {
     [
       { ...marsSwapperOsmosisQueryKeys.address(contractAddress)[0], method: 'owner', args },
     ] as const,
+  maxSlippage: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      { ...marsSwapperOsmosisQueryKeys.address(contractAddress)[0], method: 'max_slippage', args },
+    ] as const,
   route: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
     [
       { ...marsSwapperOsmosisQueryKeys.address(contractAddress)[0], method: 'route', args },
     ] as const,
+  findRoute: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      { ...marsSwapperOsmosisQueryKeys.address(contractAddress)[0], method: 'find_route', args },
+    ] as const,
+  routeCandidates: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      {
+        ...marsSwapperOsmosisQueryKeys.address(contractAddress)[0],
+        method: 'route_candidates',
+        args,
+      },
+    ] as const,
   routes: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
     [
       { ...marsSwapperOsmosisQueryKeys.address(contractAddress)[0], method: 'routes', args },
@@ -57,6 +81,14 @@ export const marsSwapperOsmosisQueryKeys = {
         args,
       },
     ] as const,
+  estimateExactOutSwap: (contractAddress: string | undefined, args?: Record<string, unknown>) =>
+    [
+      {
+        ...marsSwapperOsmosisQueryKeys.address(contractAddress)[0],
+        method: 'estimate_exact_out_swap',
+        args,
+      },
+    ] as const,
 }
 export interface MarsSwapperOsmosisReactQuery<TResponse, TData = TResponse> {
   client: MarsSwapperOsmosisQueryClient | undefined
@@ -67,6 +99,28 @@ export interface MarsSwapperOsmosisReactQuery<TResponse, TData = TResponse> {
     initialData?: undefined
   }
 }
+export interface MarsSwapperOsmosisEstimateExactOutSwapQuery<TData>
+  extends MarsSwapperOsmosisReactQuery<EstimateExactOutSwapResponse, TData> {
+  args: {
+    coinOut: Coin
+    denomIn: string
+  }
+}
+export function useMarsSwapperOsmosisEstimateExactOutSwapQuery<
+  TData = EstimateExactOutSwapResponse,
+>({ client, args, options }: MarsSwapperOsmosisEstimateExactOutSwapQuery<TData>) {
+  return useQuery<EstimateExactOutSwapResponse, Error, TData>(
+    marsSwapperOsmosisQueryKeys.estimateExactOutSwap(client?.contractAddress, args),
+    () =>
+      client
+        ? client.estimateExactOutSwap({
+            coinOut: args.coinOut,
+            denomIn: args.denomIn,
+          })
+        : Promise.reject(new Error('Invalid client')),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
 export interface MarsSwapperOsmosisEstimateExactInSwapQuery<TData>
   extends MarsSwapperOsmosisReactQuery<EstimateExactInSwapResponse, TData> {
   args: {
@@ -92,18 +146,18 @@ export function useMarsSwapperOsmosisEstimateExactInSwapQuery<TData = EstimateEx
   )
 }
 export interface MarsSwapperOsmosisRoutesQuery<TData>
-  extends MarsSwapperOsmosisReactQuery<ArrayOfRouteResponseForEmpty, TData> {
+  extends MarsSwapperOsmosisReactQuery<RoutesResponseForEmpty, TData> {
   args: {
     limit?: number
     startAfter?: string[][]
   }
 }
-export function useMarsSwapperOsmosisRoutesQuery<TData = ArrayOfRouteResponseForEmpty>({
+export function useMarsSwapperOsmosisRoutesQuery<TData = RoutesResponseForEmpty>({
   client,
   args,
   options,
 }: MarsSwapperOsmosisRoutesQuery<TData>) {
-  return useQuery<ArrayOfRouteResponseForEmpty, Error, TData>(
+  return useQuery<RoutesResponseForEmpty, Error, TData>(
     marsSwapperOsmosisQueryKeys.routes(client?.contractAddress, args),
     () =>
       client
@@ -115,6 +169,54 @@ export function useMarsSwapperOsmosisRoutesQuery<TData = ArrayOfRouteResponseFor
     { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
   )
 }
+export interface MarsSwapperOsmosisRouteCandidatesQuery<TData>
+  extends MarsSwapperOsmosisReactQuery<RouteCandidatesResponseForEmpty, TData> {
+  args: {
+    coinIn: Coin
+    denomOut: string
+  }
+}
+export function useMarsSwapperOsmosisRouteCandidatesQuery<TData = RouteCandidatesResponseForEmpty>({
+  client,
+  args,
+  options,
+}: MarsSwapperOsmosisRouteCandidatesQuery<TData>) {
+  return useQuery<RouteCandidatesResponseForEmpty, Error, TData>(
+    marsSwapperOsmosisQueryKeys.routeCandidates(client?.contractAddress, args),
+    () =>
+      client
+        ? client.routeCandidates({
+            coinIn: args.coinIn,
+            denomOut: args.denomOut,
+          })
+        : Promise.reject(new Error('Invalid client')),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
+export interface MarsSwapperOsmosisFindRouteQuery<TData>
+  extends MarsSwapperOsmosisReactQuery<FindRouteResponseForEmpty, TData> {
+  args: {
+    denomIn: string
+    denomOut: string
+  }
+}
+export function useMarsSwapperOsmosisFindRouteQuery<TData = FindRouteResponseForEmpty>({
+  client,
+  args,
+  options,
+}: MarsSwapperOsmosisFindRouteQuery<TData>) {
+  return useQuery<FindRouteResponseForEmpty, Error, TData>(
+    marsSwapperOsmosisQueryKeys.findRoute(client?.contractAddress, args),
+    () =>
+      client
+        ? client.findRoute({
+            denomIn: args.denomIn,
+            denomOut: args.denomOut,
+          })
+        : Promise.reject(new Error('Invalid client')),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
 export interface MarsSwapperOsmosisRouteQuery<TData>
   extends MarsSwapperOsmosisReactQuery<RouteResponseForEmpty, TData> {
   args: {
@@ -139,6 +241,18 @@ export function useMarsSwapperOsmosisRouteQuery<TData = RouteResponseForEmpty>({
     { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
   )
 }
+export interface MarsSwapperOsmosisMaxSlippageQuery<TData>
+  extends MarsSwapperOsmosisReactQuery<Decimal, TData> {}
+export function useMarsSwapperOsmosisMaxSlippageQuery<TData = Decimal>({
+  client,
+  options,
+}: MarsSwapperOsmosisMaxSlippageQuery<TData>) {
+  return useQuery<Decimal, Error, TData>(
+    marsSwapperOsmosisQueryKeys.maxSlippage(client?.contractAddress),
+    () => (client ? client.maxSlippage() : Promise.reject(new Error('Invalid client'))),
+    { ...options, enabled: !!client && (options?.enabled != undefined ? options.enabled : true) },
+  )
+}
 export interface MarsSwapperOsmosisOwnerQuery<TData>
   extends MarsSwapperOsmosisReactQuery<OwnerResponse, TData> {}
 export function useMarsSwapperOsmosisOwnerQuery<TData = OwnerResponse>({
@@ -154,8 +268,12 @@ export function useMarsSwapperOsmosisOwnerQuery<TData = OwnerResponse>({
 export interface MarsSwapperOsmosisTransferResultMutation {
   client: MarsSwapperOsmosisClient
   msg: {
+    callback?: Binary
     denomIn: string
+    denomInBalanceBefore: Uint128
     denomOut: string
+    denomOutBalanceBefore: Uint128
+    minReceive?: Uint128
     recipient: Addr
   }
   args?: {
@@ -176,12 +294,41 @@ export function useMarsSwapperOsmosisTransferResultMutation(
     options,
   )
 }
+export interface MarsSwapperOsmosisSwapExactOutMutation {
+  client: MarsSwapperOsmosisClient
+  msg: {
+    coinOut: Coin
+    denomIn: string
+    maxSlippage: Decimal
+  }
+  args?: {
+    fee?: number | StdFee | 'auto'
+    memo?: string
+    funds?: Coin[]
+  }
+}
+export function useMarsSwapperOsmosisSwapExactOutMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, MarsSwapperOsmosisSwapExactOutMutation>,
+    'mutationFn'
+  >,
+) {
+  return useMutation<ExecuteResult, Error, MarsSwapperOsmosisSwapExactOutMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.swapExactOut(msg, fee, memo, funds),
+    options,
+  )
+}
 export interface MarsSwapperOsmosisSwapExactInMutation {
   client: MarsSwapperOsmosisClient
   msg: {
+    callback?: Binary
     coinIn: Coin
     denomOut: string
+    minReceive?: Uint128
+    recipient?: string
     slippage: Decimal
+    split?: SwapSplit
   }
   args?: {
     fee?: number | StdFee | 'auto'
@@ -200,9 +347,80 @@ export function useMarsSwapperOsmosisSwapExactInMutation(
     options,
   )
 }
+export interface MarsSwapperOsmosisSetRouteCandidatesMutation {
+  client: MarsSwapperOsmosisClient
+  msg: {
+    denomIn: string
+    denomOut: string
+    routes: OsmosisRoute[]
+  }
+  args?: {
+    fee?: number | StdFee | 'auto'
+    memo?: string
+    funds?: Coin[]
+  }
+}
+export function useMarsSwapperOsmosisSetRouteCandidatesMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, MarsSwapperOsmosisSetRouteCandidatesMutation>,
+    'mutationFn'
+  >,
+) {
+  return useMutation<ExecuteResult, Error, MarsSwapperOsmosisSetRouteCandidatesMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.setRouteCandidates(msg, fee, memo, funds),
+    options,
+  )
+}
+export interface MarsSwapperOsmosisRemoveRouteMutation {
+  client: MarsSwapperOsmosisClient
+  msg: {
+    denomIn: string
+    denomOut: string
+  }
+  args?: {
+    fee?: number | StdFee | 'auto'
+    memo?: string
+    funds?: Coin[]
+  }
+}
+export function useMarsSwapperOsmosisRemoveRouteMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, MarsSwapperOsmosisRemoveRouteMutation>,
+    'mutationFn'
+  >,
+) {
+  return useMutation<ExecuteResult, Error, MarsSwapperOsmosisRemoveRouteMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) => client.removeRoute(msg, fee, memo, funds),
+    options,
+  )
+}
+export interface MarsSwapperOsmosisSetRoutesMutation {
+  client: MarsSwapperOsmosisClient
+  msg: {
+    routes: PairRouteForOsmosisRoute[]
+  }
+  args?: {
+    fee?: number | StdFee | 'auto'
+    memo?: string
+    funds?: Coin[]
+  }
+}
+export function useMarsSwapperOsmosisSetRoutesMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, MarsSwapperOsmosisSetRoutesMutation>,
+    'mutationFn'
+  >,
+) {
+  return useMutation<ExecuteResult, Error, MarsSwapperOsmosisSetRoutesMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) => client.setRoutes(msg, fee, memo, funds),
+    options,
+  )
+}
 export interface MarsSwapperOsmosisSetRouteMutation {
   client: MarsSwapperOsmosisClient
   msg: {
+    alsoReverse?: boolean
     denomIn: string
     denomOut: string
     route: OsmosisRoute
@@ -224,6 +442,29 @@ export function useMarsSwapperOsmosisSetRouteMutation(
     options,
   )
 }
+export interface MarsSwapperOsmosisUpdateMaxSlippageMutation {
+  client: MarsSwapperOsmosisClient
+  msg: {
+    maxSlippage: Decimal
+  }
+  args?: {
+    fee?: number | StdFee | 'auto'
+    memo?: string
+    funds?: Coin[]
+  }
+}
+export function useMarsSwapperOsmosisUpdateMaxSlippageMutation(
+  options?: Omit<
+    UseMutationOptions<ExecuteResult, Error, MarsSwapperOsmosisUpdateMaxSlippageMutation>,
+    'mutationFn'
+  >,
+) {
+  return useMutation<ExecuteResult, Error, MarsSwapperOsmosisUpdateMaxSlippageMutation>(
+    ({ client, msg, args: { fee, memo, funds } = {} }) =>
+      client.updateMaxSlippage(msg, fee, memo, funds),
+    options,
+  )
+}
 export interface MarsSwapperOsmosisUpdateOwnerMutation {
   client: MarsSwapperOsmosisClient
   msg: OwnerUpdate
@@ -5,31 +5,72 @@
  * and run the @cosmwasm/ts-codegen generate command to regenerate this file.
  */
 
+export type Decimal = string
 export interface InstantiateMsg {
+  max_slippage: Decimal
   owner: string
 }
 export type ExecuteMsg =
   | {
       update_owner: OwnerUpdate
     }
+  | {
+      update_max_slippage: {
+        max_slippage: Decimal
+      }
+    }
   | {
       set_route: {
+        also_reverse?: boolean
         denom_in: string
         denom_out: string
         route: OsmosisRoute
       }
     }
+  | {
+      set_routes: {
+        routes: PairRouteForOsmosisRoute[]
+      }
+    }
+  | {
+      remove_route: {
+        denom_in: string
+        denom_out: string
+      }
+    }
+  | {
+      set_route_candidates: {
+        denom_in: string
+        denom_out: string
+        routes: OsmosisRoute[]
+      }
+    }
   | {
       swap_exact_in: {
+        callback?: Binary | null
         coin_in: Coin
         denom_out: string
+        min_receive?: Uint128 | null
+        recipient?: string | null
         slippage: Decimal
+        split?: SwapSplit | null
+      }
+    }
+  | {
+      swap_exact_out: {
+        coin_out: Coin
+        denom_in: string
+        max_slippage: Decimal
       }
     }
   | {
       transfer_result: {
+        callback?: Binary | null
         denom_in: string
+        denom_in_balance_before: Uint128
         denom_out: string
+        denom_out_balance_before: Uint128
+        min_receive?: Uint128 | null
         recipient: Addr
       }
     }
@@ -49,14 +90,22 @@ export type OwnerUpdate =
     }
   | 'clear_emergency_owner'
 export type OsmosisRoute = SwapAmountInRoute[]
+export type Binary = string
 export type Uint128 = string
-export type Decimal = string
+export type SwapSplit = {
+  weights: Decimal[]
+}
 export type Addr = string
 export interface SwapAmountInRoute {
   pool_id: number
   token_out_denom: string
   [k: string]: unknown
 }
+export interface PairRouteForOsmosisRoute {
+  denom_in: string
+  denom_out: string
+  route: OsmosisRoute
+}
 export interface Coin {
   amount: Uint128
   denom: string
@@ -66,12 +115,27 @@ export type QueryMsg =
   | {
       owner: {}
     }
+  | {
+      max_slippage: {}
+    }
   | {
       route: {
         denom_in: string
         denom_out: string
       }
     }
+  | {
+      find_route: {
+        denom_in: string
+        denom_out: string
+      }
+    }
+  | {
+      route_candidates: {
+        coin_in: Coin
+        denom_out: string
+      }
+    }
   | {
       routes: {
         limit?: number | null
@@ -84,9 +148,28 @@ export type QueryMsg =
         denom_out: string
       }
     }
+  | {
+      estimate_exact_out_swap: {
+        coin_out: Coin
+        denom_in: string
+      }
+    }
+export interface MigrateMsg {
+  max_slippage: Decimal
+}
 export interface EstimateExactInSwapResponse {
   amount: Uint128
 }
+export interface EstimateExactOutSwapResponse {
+  amount: Uint128
+}
+export interface FindRouteResponseForEmpty {
+  denoms: string[]
+  route: Empty
+}
+export interface Empty {
+  [k: string]: unknown
+}
 export interface OwnerResponse {
   abolished: boolean
   emergency_owner?: string | null
@@ -99,7 +182,15 @@ export interface RouteResponseForEmpty {
   denom_out: string
   route: Empty
 }
-export interface Empty {
-  [k: string]: unknown
+export interface RouteCandidatesResponseForEmpty {
+  candidates: RouteCandidateForEmpty[]
+}
+export interface RouteCandidateForEmpty {
+  amount_out?: Uint128 | null
+  route: Empty
+}
+export interface RoutesResponseForEmpty {
+  data: RouteResponseForEmpty[]
+  next_start_after?: [string, string] | null
+  total_count: number
 }
-export type ArrayOfRouteResponseForEmpty = RouteResponseForEmpty[]